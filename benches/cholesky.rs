@@ -0,0 +1,44 @@
+use std::time::{ Duration, Instant };
+
+use pathsim::prelude::*;
+
+// Usage: cargo bench --bench cholesky -- [size ...]
+fn main() {
+    let sizes: Vec<usize> = std::env::args().skip(1).filter_map(|arg| arg.parse().ok()).collect();
+    let sizes = if sizes.is_empty() { vec![100, 250, 500, 1000] } else { sizes };
+    println!("{:>6} {:>14} {:>14} {:>14} {:>14}", "n", "cholesky", "blocked(32)", "blocked(64)", "blocked(128)");
+    for n in sizes {
+        let a = covariance(n);
+        let reference = time(|| a.cholesky().unwrap());
+        let blocked: Vec<Duration> = [32, 64, 128].iter().map(|&block_size| time(|| a.cholesky_blocked(block_size).unwrap())).collect();
+        println!("{:>6} {:>14?} {:>14?} {:>14?} {:>14?}", n, reference, blocked[0], blocked[1], blocked[2]);
+    }
+}
+
+// X·Xᵀ + n·I for a pseudo random X, built directly to keep setup cheap
+fn covariance(n: usize) -> Matrix<f64> {
+    let mut state = 0x2545f4914f6cdd1du64;
+    let x: Vec<f64> = (0..n * n).map(|_| {
+        state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        (state >> 11) as f64 / (1u64 << 53) as f64 - 0.5f64
+    }).collect();
+    let mut data = vec![0f64; n * n];
+    for i in 0..n {
+        for j in 0..=i {
+            let sum: f64 = x[i * n..(i + 1) * n].iter().zip(&x[j * n..(j + 1) * n]).map(|(a, b)| a * b).sum();
+            data[i * n + j] = sum;
+            data[j * n + i] = sum;
+        }
+        data[i * n + i] += n as f64;
+    }
+    MatrixBuilder::new().from_vec(n, n, data).build().unwrap()
+}
+
+fn time<F, R>(f: F) -> Duration where F: Fn() -> R {
+    let runs = 3;
+    (0..runs).map(|_| {
+        let start = Instant::now();
+        std::hint::black_box(f());
+        start.elapsed()
+    }).min().unwrap()
+}
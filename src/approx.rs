@@ -0,0 +1,74 @@
+use crate::numeric::Numeric;
+use crate::complex::Complex;
+use crate::matrix::Matrix;
+use crate::arithmetic::MatrixDimensionMismatchError;
+use crate::tolerance::Tolerance;
+
+// Largest element-wise distance between two matrices
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ElementDifference {
+    pub position: (usize, usize),
+    pub difference: f64,
+}
+
+// Elements are compared as Complex<f64>, real and imaginary parts each within the tolerance,
+// so matrices of different element types such as f32 and f64 can be compared directly
+impl<T> Matrix<T> where T: Numeric {
+
+    // Ulps are counted at the coarser precision of T and S, so f32 against f64 is measured in f32 units
+    pub fn approx_eq<S>(&self, other: &Matrix<S>, tolerance: Tolerance) -> bool where S: Numeric {
+        let digits = T::significand_digits().min(S::significand_digits());
+        self.rows() == other.rows() &&
+        self.cols() == other.cols() &&
+        self.data().iter().zip(other.data().iter()).all(|(a, b)| {
+            let (a, b) = (a.to::<Complex<f64>>(), b.to::<Complex<f64>>());
+            tolerance.is_within_digits(a.re, b.re, digits) && tolerance.is_within_digits(a.im, b.im, digits)
+        })
+    }
+
+    pub fn abs_diff<S>(&self, other: &Matrix<S>) -> Result<Matrix<f64>, MatrixDimensionMismatchError> where S: Numeric {
+        if self.rows() != other.rows() || self.cols() != other.cols() {
+            return Err(MatrixDimensionMismatchError { left: (self.rows(), self.cols()), right: (other.rows(), other.cols()) })
+        }
+        let data = self.data().iter().zip(other.data().iter())
+            .map(|(a, b)| (a.to::<Complex<f64>>() - b.to::<Complex<f64>>()).norm())
+            .collect();
+        Ok(Matrix::from_parts(self.rows(), self.cols(), data))
+    }
+
+    // NaN differences are reported as the worst
+    pub fn max_abs_diff<S>(&self, other: &Matrix<S>) -> Result<ElementDifference, MatrixDimensionMismatchError> where S: Numeric {
+        let diff = self.abs_diff(other)?;
+        let worst = diff.data().iter().enumerate()
+            .max_by(|(_, a), (_, b)| a.is_nan().cmp(&b.is_nan()).then(a.total_cmp(b)))
+            .map(|(k, &difference)| ElementDifference { position: (k / diff.cols(), k % diff.cols()), difference })
+            .unwrap_or(ElementDifference { position: (0, 0), difference: 0f64 });
+        Ok(worst)
+    }
+
+}
+
+#[doc(hidden)]
+pub fn approx_eq_failure<T, S>(left: &Matrix<T>, right: &Matrix<S>, tolerance: Tolerance) -> String where T: Numeric, S: Numeric {
+    match left.max_abs_diff(right) {
+        Ok(worst) => format!(
+            "matrices are not equal within {:?}\nleft:{}right:{}difference:{}largest difference {} at ({}, {})",
+            tolerance, left, right, left.abs_diff(right).unwrap(), worst.difference, worst.position.0, worst.position.1
+        ),
+        Err(error) => format!("matrices are not equal: {}", error),
+    }
+}
+
+// Panics with both matrices, their element-wise difference and the worst element when they differ
+#[macro_export]
+macro_rules! assert_matrix_approx_eq {
+    ($left:expr, $right:expr, $tolerance:expr $(,)?) => {
+        match (&$left, &$right, $tolerance) {
+            (left, right, tolerance) => {
+                if !left.approx_eq(right, tolerance) {
+                    panic!("{}", $crate::approx_eq_failure(left, right, tolerance));
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,362 @@
+use std::ops;
+use std::fmt;
+
+use crate::numeric::{ Numeric, ThreadSafe };
+use crate::matrix::Matrix;
+
+#[derive(Debug, PartialEq)]
+pub struct MatrixDimensionMismatchError {
+    pub left: (usize, usize),
+    pub right: (usize, usize),
+}
+
+impl fmt::Display for MatrixDimensionMismatchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f, 
+            "Matrix dimensions are not compatible: {}x{} and {}x{}", 
+            self.left.0, self.left.1, self.right.0, self.right.1
+        )
+    }
+}
+
+impl std::error::Error for MatrixDimensionMismatchError {}
+
+#[derive(Debug, PartialEq)]
+pub enum MatrixArithmeticError {
+    DimensionMismatch(MatrixDimensionMismatchError),
+    Overflow { position: (usize, usize) },
+}
+
+impl fmt::Display for MatrixArithmeticError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MatrixArithmeticError::DimensionMismatch(error) => write!(f, "{}", error),
+            MatrixArithmeticError::Overflow { position } => {
+                write!(f, "Matrix arithmetic overflowed at ({}, {})", position.0, position.1)
+            },
+        }
+    }
+}
+
+impl std::error::Error for MatrixArithmeticError {}
+
+// Element types whose arithmetic can overflow, with the primitive checked_* semantics
+pub trait CheckedArithmetic: Numeric {
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! impl_checked_arithmetic {
+    ($($t:ty),*) => {
+        $(
+            impl CheckedArithmetic for $t {
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$t>::checked_add(self, other)
+                }
+
+                fn checked_sub(self, other: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, other)
+                }
+
+                fn checked_mul(self, other: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, other)
+                }
+            }
+        )*
+    };
+}
+
+impl_checked_arithmetic!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl<T> Matrix<T> where T: Numeric {
+
+    fn dimension_mismatch(&self, other: &Matrix<T>) -> MatrixDimensionMismatchError {
+        MatrixDimensionMismatchError {
+            left: (self.rows(), self.cols()),
+            right: (other.rows(), other.cols()),
+        }
+    }
+
+    fn zip_with<F>(&self, other: &Matrix<T>, f: F) -> Result<Matrix<T>, MatrixDimensionMismatchError> 
+    where F: Fn(T, T) -> T {
+        if self.rows() != other.rows() || self.cols() != other.cols() {
+            return Err(self.dimension_mismatch(other))
+        }
+        let data = self.data().iter().zip(other.data().iter()).map(|(&a, &b)| f(a, b)).collect();
+        Ok(Matrix::from_parts(self.rows(), self.cols(), data))
+    }
+
+    fn map<F>(&self, f: F) -> Matrix<T> where F: Fn(T) -> T {
+        Matrix::from_parts(self.rows(), self.cols(), self.data().iter().map(|&a| f(a)).collect())
+    }
+
+    pub fn checked_add(&self, other: &Matrix<T>) -> Result<Matrix<T>, MatrixDimensionMismatchError> {
+        self.zip_with(other, |a, b| a + b)
+    }
+
+    pub fn checked_sub(&self, other: &Matrix<T>) -> Result<Matrix<T>, MatrixDimensionMismatchError> {
+        self.zip_with(other, |a, b| a - b)
+    }
+
+    pub fn checked_matmul(&self, other: &Matrix<T>) -> Result<Matrix<T>, MatrixDimensionMismatchError> where T: ThreadSafe {
+        self.matmul_on(other, crate::worker_threads())
+    }
+
+    #[cfg_attr(not(feature = "parallel"), allow(unused_variables))]
+    pub(crate) fn matmul_on(&self, other: &Matrix<T>, threads: usize) -> Result<Matrix<T>, MatrixDimensionMismatchError> where T: ThreadSafe {
+        if self.cols() != other.rows() {
+            return Err(self.dimension_mismatch(other))
+        }
+        let (n, m, p) = (self.rows(), self.cols(), other.cols());
+        let a = self.data();
+        let b = other.data();
+        let mut c = vec![T::zero(); n * p];
+        #[cfg(feature = "parallel")]
+        if n * m * p >= crate::parallel::MIN_PARALLEL_WORK {
+            crate::parallel::for_each_row(&mut c, p, threads, |i, c_row| multiply_row(&a[i * m..(i + 1) * m], b, c_row));
+            return Ok(Matrix::from_parts(n, p, c))
+        }
+        for i in 0..n {
+            multiply_row(&a[i * m..(i + 1) * m], b, &mut c[i * p..(i + 1) * p]);
+        }
+        Ok(Matrix::from_parts(n, p, c))
+    }
+
+}
+
+// Same operations as checked_add, checked_sub, matrix and scalar products, reporting overflow instead of
+// panicking in debug builds or wrapping in release builds
+impl<T> Matrix<T> where T: CheckedArithmetic {
+
+    fn try_zip_with<F>(&self, other: &Matrix<T>, f: F) -> Result<Matrix<T>, MatrixArithmeticError>
+    where F: Fn(T, T) -> Option<T> {
+        if self.rows() != other.rows() || self.cols() != other.cols() {
+            return Err(MatrixArithmeticError::DimensionMismatch(self.dimension_mismatch(other)))
+        }
+        let mut data = Vec::with_capacity(self.data().len());
+        for (k, (&a, &b)) in self.data().iter().zip(other.data().iter()).enumerate() {
+            data.push(f(a, b).ok_or(MatrixArithmeticError::Overflow { position: (k / self.cols(), k % self.cols()) })?);
+        }
+        Ok(Matrix::from_parts(self.rows(), self.cols(), data))
+    }
+
+    pub fn try_add(&self, other: &Matrix<T>) -> Result<Matrix<T>, MatrixArithmeticError> {
+        self.try_zip_with(other, T::checked_add)
+    }
+
+    pub fn try_sub(&self, other: &Matrix<T>) -> Result<Matrix<T>, MatrixArithmeticError> {
+        self.try_zip_with(other, T::checked_sub)
+    }
+
+    pub fn try_scale(&self, scalar: T) -> Result<Matrix<T>, MatrixArithmeticError> {
+        let mut data = Vec::with_capacity(self.data().len());
+        for (k, &a) in self.data().iter().enumerate() {
+            data.push(a.checked_mul(scalar).ok_or(MatrixArithmeticError::Overflow { position: (k / self.cols(), k % self.cols()) })?);
+        }
+        Ok(Matrix::from_parts(self.rows(), self.cols(), data))
+    }
+
+    pub fn try_matmul(&self, other: &Matrix<T>) -> Result<Matrix<T>, MatrixArithmeticError> {
+        if self.cols() != other.rows() {
+            return Err(MatrixArithmeticError::DimensionMismatch(self.dimension_mismatch(other)))
+        }
+        let (n, m, p) = (self.rows(), self.cols(), other.cols());
+        let mut c = Matrix::from_parts(n, p, vec![T::zero(); n * p]);
+        for i in 0..n {
+            for j in 0..p {
+                let mut sum = T::zero();
+                for k in 0..m {
+                    sum = self[(i, k)].checked_mul(other[(k, j)])
+                        .and_then(|product| sum.checked_add(product))
+                        .ok_or(MatrixArithmeticError::Overflow { position: (i, j) })?;
+                }
+                c[(i, j)] = sum;
+            }
+        }
+        Ok(c)
+    }
+
+}
+
+// c_row += a_row·B, accumulated one row of B at a time
+fn multiply_row<T>(a_row: &[T], b: &[T], c_row: &mut [T]) where T: Numeric {
+    let p = c_row.len();
+    for (k, &a_ik) in a_row.iter().enumerate() {
+        let b_row = &b[k * p..(k + 1) * p];
+        for (c_ij, &b_kj) in c_row.iter_mut().zip(b_row.iter()) {
+            *c_ij = *c_ij + a_ik * b_kj;
+        }
+    }
+}
+
+fn unwrap_dimensions<T>(result: Result<Matrix<T>, MatrixDimensionMismatchError>) -> Matrix<T> where T: Numeric {
+    match result {
+        Ok(matrix) => matrix,
+        Err(error) => panic!("{}", error),
+    }
+}
+
+macro_rules! impl_elementwise_op {
+    ($op_trait:ident, $op_fn:ident, $checked:ident, $assign_trait:ident, $assign_fn:ident) => {
+        impl<T> ops::$op_trait<&Matrix<T>> for &Matrix<T> where T: Numeric {
+            type Output = Matrix<T>;
+
+            fn $op_fn(self, other: &Matrix<T>) -> Self::Output {
+                unwrap_dimensions(self.$checked(other))
+            }
+        }
+
+        impl<T> ops::$op_trait<Matrix<T>> for &Matrix<T> where T: Numeric {
+            type Output = Matrix<T>;
+
+            fn $op_fn(self, other: Matrix<T>) -> Self::Output {
+                unwrap_dimensions(self.$checked(&other))
+            }
+        }
+
+        impl<T> ops::$op_trait<&Matrix<T>> for Matrix<T> where T: Numeric {
+            type Output = Matrix<T>;
+
+            fn $op_fn(self, other: &Matrix<T>) -> Self::Output {
+                unwrap_dimensions(self.$checked(other))
+            }
+        }
+
+        impl<T> ops::$op_trait<Matrix<T>> for Matrix<T> where T: Numeric {
+            type Output = Matrix<T>;
+
+            fn $op_fn(self, other: Matrix<T>) -> Self::Output {
+                unwrap_dimensions(self.$checked(&other))
+            }
+        }
+
+        impl<T> ops::$assign_trait<&Matrix<T>> for Matrix<T> where T: Numeric {
+            fn $assign_fn(&mut self, other: &Matrix<T>) {
+                if self.rows() != other.rows() || self.cols() != other.cols() {
+                    panic!("{}", self.dimension_mismatch(other));
+                }
+                for (a, &b) in self.data_mut().iter_mut().zip(other.data().iter()) {
+                    *a = ops::$op_trait::$op_fn(*a, b);
+                }
+            }
+        }
+
+        impl<T> ops::$assign_trait<Matrix<T>> for Matrix<T> where T: Numeric {
+            fn $assign_fn(&mut self, other: Matrix<T>) {
+                ops::$assign_trait::$assign_fn(self, &other)
+            }
+        }
+    };
+}
+
+impl_elementwise_op!(Add, add, checked_add, AddAssign, add_assign);
+impl_elementwise_op!(Sub, sub, checked_sub, SubAssign, sub_assign);
+
+impl<T> ops::Neg for &Matrix<T> where T: Numeric + ops::Neg<Output = T> {
+    type Output = Matrix<T>;
+
+    fn neg(self) -> Self::Output {
+        self.map(|a| -a)
+    }
+}
+
+impl<T> ops::Neg for Matrix<T> where T: Numeric + ops::Neg<Output = T> {
+    type Output = Matrix<T>;
+
+    fn neg(mut self) -> Self::Output {
+        for a in self.data_mut().iter_mut() { *a = -*a; }
+        self
+    }
+}
+
+impl<T> ops::Mul<T> for &Matrix<T> where T: Numeric {
+    type Output = Matrix<T>;
+
+    fn mul(self, scalar: T) -> Self::Output {
+        self.map(|a| a * scalar)
+    }
+}
+
+impl<T> ops::Mul<T> for Matrix<T> where T: Numeric {
+    type Output = Matrix<T>;
+
+    fn mul(mut self, scalar: T) -> Self::Output {
+        self *= scalar;
+        self
+    }
+}
+
+impl<T> ops::MulAssign<T> for Matrix<T> where T: Numeric {
+    fn mul_assign(&mut self, scalar: T) {
+        for a in self.data_mut().iter_mut() { *a = *a * scalar; }
+    }
+}
+
+impl<T> ops::Div<T> for &Matrix<T> where T: Numeric {
+    type Output = Matrix<T>;
+
+    fn div(self, scalar: T) -> Self::Output {
+        self.map(|a| a / scalar)
+    }
+}
+
+impl<T> ops::Div<T> for Matrix<T> where T: Numeric {
+    type Output = Matrix<T>;
+
+    fn div(mut self, scalar: T) -> Self::Output {
+        self /= scalar;
+        self
+    }
+}
+
+impl<T> ops::DivAssign<T> for Matrix<T> where T: Numeric {
+    fn div_assign(&mut self, scalar: T) {
+        for a in self.data_mut().iter_mut() { *a = *a / scalar; }
+    }
+}
+
+impl<T> ops::Mul<&Matrix<T>> for &Matrix<T> where T: Numeric + ThreadSafe {
+    type Output = Matrix<T>;
+
+    fn mul(self, other: &Matrix<T>) -> Self::Output {
+        unwrap_dimensions(self.checked_matmul(other))
+    }
+}
+
+impl<T> ops::Mul<Matrix<T>> for &Matrix<T> where T: Numeric + ThreadSafe {
+    type Output = Matrix<T>;
+
+    fn mul(self, other: Matrix<T>) -> Self::Output {
+        unwrap_dimensions(self.checked_matmul(&other))
+    }
+}
+
+impl<T> ops::Mul<&Matrix<T>> for Matrix<T> where T: Numeric + ThreadSafe {
+    type Output = Matrix<T>;
+
+    fn mul(self, other: &Matrix<T>) -> Self::Output {
+        unwrap_dimensions(self.checked_matmul(other))
+    }
+}
+
+impl<T> ops::Mul<Matrix<T>> for Matrix<T> where T: Numeric + ThreadSafe {
+    type Output = Matrix<T>;
+
+    fn mul(self, other: Matrix<T>) -> Self::Output {
+        unwrap_dimensions(self.checked_matmul(&other))
+    }
+}
+
+impl<T> ops::MulAssign<&Matrix<T>> for Matrix<T> where T: Numeric + ThreadSafe {
+    fn mul_assign(&mut self, other: &Matrix<T>) {
+        *self = unwrap_dimensions(self.checked_matmul(other));
+    }
+}
+
+impl<T> ops::MulAssign<Matrix<T>> for Matrix<T> where T: Numeric + ThreadSafe {
+    fn mul_assign(&mut self, other: Matrix<T>) {
+        *self = unwrap_dimensions(self.checked_matmul(&other));
+    }
+}
@@ -0,0 +1,175 @@
+use std::fmt;
+use std::ops;
+use std::cmp;
+
+use crate::numeric::{ Numeric, Float, NumericConversionError };
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Complex<F> {
+    pub re: F,
+    pub im: F,
+}
+
+impl<F> Complex<F> where F: Numeric {
+
+    pub fn new(re: F, im: F) -> Self {
+        Complex { re, im }
+    }
+
+    pub fn conj(&self) -> Self {
+        Complex { re: self.re, im: F::zero() - self.im }
+    }
+
+    pub fn norm_sqr(&self) -> F {
+        self.re * self.re + self.im * self.im
+    }
+
+    pub fn is_real(&self) -> bool {
+        self.im == F::zero()
+    }
+
+}
+
+impl<F> Complex<F> where F: Float {
+    pub fn norm(&self) -> F {
+        self.re.hypot(self.im)
+    }
+}
+
+impl<F> fmt::Display for Complex<F> where F: Numeric {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.im < F::zero() {
+            write!(f, "{}-{}i", self.re, F::zero() - self.im)
+        } else {
+            write!(f, "{}+{}i", self.re, self.im)
+        }
+    }
+}
+
+// Complex numbers are unordered, only values on the real line compare
+impl<F> PartialOrd for Complex<F> where F: Numeric {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        if self.im == F::zero() && other.im == F::zero() {
+            self.re.partial_cmp(&other.re)
+        } else if self == other {
+            Some(cmp::Ordering::Equal)
+        } else {
+            None
+        }
+    }
+}
+
+impl<F> ops::Add for Complex<F> where F: Numeric {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Complex { re: self.re + other.re, im: self.im + other.im }
+    }
+}
+
+impl<F> ops::Sub for Complex<F> where F: Numeric {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Complex { re: self.re - other.re, im: self.im - other.im }
+    }
+}
+
+impl<F> ops::Mul for Complex<F> where F: Numeric {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Complex {
+            re: self.re * other.re - self.im * other.im,
+            im: self.re * other.im + self.im * other.re,
+        }
+    }
+}
+
+impl<F> ops::Div for Complex<F> where F: Numeric {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        let denominator = other.norm_sqr();
+        Complex {
+            re: (self.re * other.re + self.im * other.im) / denominator,
+            im: (self.im * other.re - self.re * other.im) / denominator,
+        }
+    }
+}
+
+impl<F> ops::Neg for Complex<F> where F: Numeric {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Complex { re: F::zero() - self.re, im: F::zero() - self.im }
+    }
+}
+
+// Real numbers embed with a zero imaginary part, converting to a real type keeps the real part
+impl<F> Numeric for Complex<F> where F: Float {
+    fn one() -> Self {
+        Complex::new(F::one(), F::zero())
+    }
+
+    fn zero() -> Self {
+        Complex::new(F::zero(), F::zero())
+    }
+
+    fn from_i32(value: i32) -> Self {
+        Complex::new(F::from_i32(value), F::zero())
+    }
+
+    fn from_i64(value: i64) -> Self {
+        Complex::new(F::from_i64(value), F::zero())
+    }
+
+    fn from_usize(value: usize) -> Self {
+        Complex::new(F::from_usize(value), F::zero())
+    }
+
+    fn from_i128(value: i128) -> Self {
+        Complex::new(F::from_i128(value), F::zero())
+    }
+
+    fn from_u128(value: u128) -> Self {
+        Complex::new(F::from_u128(value), F::zero())
+    }
+
+    fn from_f32(value: f32) -> Self {
+        Complex::new(F::from_f32(value), F::zero())
+    }
+
+    fn from_f64(value: f64) -> Self {
+        Complex::new(F::from_f64(value), F::zero())
+    }
+
+    fn from_complex(value: Complex<f64>) -> Self {
+        Complex::new(F::from_f64(value.re), F::from_f64(value.im))
+    }
+
+    fn try_from_i128(value: i128) -> Option<Self> {
+        Some(Complex::new(F::try_from_i128(value)?, F::zero()))
+    }
+
+    fn try_from_u128(value: u128) -> Option<Self> {
+        Some(Complex::new(F::try_from_u128(value)?, F::zero()))
+    }
+
+    fn try_from_complex(value: Complex<f64>) -> Option<Self> {
+        Some(Complex::new(F::try_from_f64(value.re)?, F::try_from_f64(value.im)?))
+    }
+
+    fn significand_digits() -> u32 {
+        F::significand_digits()
+    }
+
+    fn to<S> (&self) -> S where S: Numeric {
+        S::from_complex(Complex::new(self.re.to::<f64>(), self.im.to::<f64>()))
+    }
+
+    fn try_to<S> (&self) -> Result<S, NumericConversionError> where S: Numeric {
+        S::try_from_complex(Complex::new(self.re.to::<f64>(), self.im.to::<f64>())).ok_or_else(|| NumericConversionError::new(self))
+    }
+
+}
@@ -0,0 +1,154 @@
+use std::fmt;
+
+use crate::numeric::Real;
+use crate::matrix::{ Matrix, MatrixBuilder };
+use crate::symmetric::{ Symmetric, Algorithm };
+
+#[derive(Debug, PartialEq)]
+pub enum NearestCorrelationError {
+    MatrixIsNotSymmetric,
+    DimensionMismatch,
+    WeightIsNotPositive,
+    FloorIsNotPositive,
+    EigenDecompositionFailed,
+    AlgorithmDidNotConverge,
+}
+
+impl fmt::Display for NearestCorrelationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            NearestCorrelationError::MatrixIsNotSymmetric => {
+                write!(f, "Nearest correlation matrix cannot be computed because matrix is not symmetric.")
+            },
+            NearestCorrelationError::DimensionMismatch => {
+                write!(f, "Weights dimensions are not consistent with the matrix.")
+            },
+            NearestCorrelationError::WeightIsNotPositive => {
+                write!(f, "Nearest correlation matrix weights must be strictly positive.")
+            },
+            NearestCorrelationError::FloorIsNotPositive => {
+                write!(f, "Correlation eigen value floor must be finite and strictly positive.")
+            },
+            NearestCorrelationError::EigenDecompositionFailed => {
+                write!(f, "Nearest correlation matrix failed because eigen decomposition failed.")
+            },
+            NearestCorrelationError::AlgorithmDidNotConverge => {
+                write!(f, "Nearest correlation matrix did not converge within the maximum number of iterations.")
+            },
+        }
+    }
+}
+
+impl std::error::Error for NearestCorrelationError {}
+
+#[derive(Debug, Clone)]
+pub struct CorrelationRepair {
+    matrix: Matrix<f64>,
+    distance: f64,
+    iterations: usize,
+}
+
+impl CorrelationRepair {
+
+    pub fn matrix(&self) -> &Matrix<f64> {
+        &self.matrix
+    }
+
+    pub fn into_matrix(self) -> Matrix<f64> {
+        self.matrix
+    }
+
+    // Frobenius norm of the difference between the repaired and the original matrix
+    pub fn distance(&self) -> f64 {
+        self.distance
+    }
+
+    pub fn iterations(&self) -> usize {
+        self.iterations
+    }
+
+}
+
+pub trait NearestCorrelation {
+    fn nearest_correlation(&self, weights: Option<&[f64]>) -> Result<CorrelationRepair, NearestCorrelationError>;
+    fn nearest_correlation_within(&self, weights: Option<&[f64]>, tolerance: f64, max_iterations: usize) -> Result<CorrelationRepair, NearestCorrelationError>;
+    fn clip_to_correlation(&self, floor: f64) -> Result<CorrelationRepair, NearestCorrelationError>;
+}
+
+impl<T> NearestCorrelation for Matrix<T> where T: Real {
+    fn nearest_correlation(&self, weights: Option<&[f64]>) -> Result<CorrelationRepair, NearestCorrelationError> {
+        self.nearest_correlation_within(weights, 1e-10, 10_000)
+    }
+
+    // Source: N. J. Higham, Computing the nearest correlation matrix, IMA J. Numer. Anal. 22 (2002)
+    // Alternating projections with Dykstra's correction, in the W-norm for W = diag(weights)
+    fn nearest_correlation_within(&self, weights: Option<&[f64]>, tolerance: f64, max_iterations: usize) -> Result<CorrelationRepair, NearestCorrelationError> {
+        if !self.is_symmetric() { return Err(NearestCorrelationError::MatrixIsNotSymmetric) }
+        let a = self.copy_to::<f64>().unwrap();
+        let n = a.rows();
+        let w = match weights {
+            Some(w) if w.len() != n => return Err(NearestCorrelationError::DimensionMismatch),
+            Some(w) if w.iter().any(|&x| x.is_nan() || x <= 0f64) => return Err(NearestCorrelationError::WeightIsNotPositive),
+            Some(w) => w.iter().map(|x| x.sqrt()).collect(),
+            None => vec![1f64; n],
+        };
+        let mut y = a.clone();
+        let mut ds = MatrixBuilder::<f64>::new().zeros(n, n).build().unwrap();
+        for iteration in 1..=max_iterations {
+            let r = &y - &ds;
+            let x = project_semi_definite(&r, &w, 0f64)?;
+            ds = &x - &r;
+            let previous = y;
+            y = x.clone();
+            for i in 0..n { y[(i, i)] = 1f64; }
+            let change = frobenius(&(&y - &previous)).max(frobenius(&(&y - &x)));
+            if change <= tolerance * frobenius(&y) {
+                return Ok(CorrelationRepair { distance: frobenius(&(&y - &a)), matrix: y, iterations: iteration })
+            }
+        }
+        Err(NearestCorrelationError::AlgorithmDidNotConverge)
+    }
+
+    // Raises eigen values to at least floor, then rescales back to a unit diagonal
+    fn clip_to_correlation(&self, floor: f64) -> Result<CorrelationRepair, NearestCorrelationError> {
+        if !self.is_symmetric() { return Err(NearestCorrelationError::MatrixIsNotSymmetric) }
+        if !floor.is_finite() || floor <= 0f64 { return Err(NearestCorrelationError::FloorIsNotPositive) }
+        let a = self.copy_to::<f64>().unwrap();
+        let n = a.rows();
+        let mut b = project_semi_definite(&a, &vec![1f64; n], floor)?;
+        let scale: Vec<f64> = (0..n).map(|i| b[(i, i)].sqrt()).collect();
+        for i in 0..n {
+            for j in 0..n {
+                b[(i, j)] = if i == j { 1f64 } else { b[(i, j)] / (scale[i] * scale[j]) };
+            }
+        }
+        Ok(CorrelationRepair { distance: frobenius(&(&b - &a)), matrix: b, iterations: 1 })
+    }
+}
+
+// W^(-1/2)·P(W^(1/2)·R·W^(1/2))·W^(-1/2), where P raises eigen values to at least floor
+fn project_semi_definite(r: &Matrix<f64>, w: &[f64], floor: f64) -> Result<Matrix<f64>, NearestCorrelationError> {
+    let n = r.rows();
+    let mut scaled = r.clone();
+    for i in 0..n {
+        for j in 0..=i {
+            scaled[(i, j)] = r[(i, j)] * w[i] * w[j];
+            scaled[(j, i)] = scaled[(i, j)];
+        }
+    }
+    let (d, v) = scaled.eigen_decomposition(Algorithm::Jacobi)
+        .map_err(|_| NearestCorrelationError::EigenDecompositionFailed)?;
+    let mut x = MatrixBuilder::<f64>::new().zeros(n, n).build().unwrap();
+    for i in 0..n {
+        for j in 0..=i {
+            let sum: f64 = (0..n).map(|k| v[(i, k)] * d[k].max(floor) * v[(j, k)]).sum();
+            x[(i, j)] = sum / (w[i] * w[j]);
+            x[(j, i)] = x[(i, j)];
+        }
+    }
+    Ok(x)
+}
+
+fn frobenius(m: &Matrix<f64>) -> f64 {
+    m.data().iter().map(|x| x * x).sum::<f64>().sqrt()
+}
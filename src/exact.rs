@@ -0,0 +1,222 @@
+use std::fmt;
+
+use crate::numeric::{ Numeric, Real, NumericConversionError };
+use crate::rational::{ Rational, RationalError };
+use crate::matrix::{ Matrix, MatrixBuilder, Swap };
+use crate::square::Square;
+
+#[derive(Debug, PartialEq)]
+pub enum ExactEliminationError {
+    MatrixIsNotSquare,
+    MatrixIsSingular,
+    DimensionMismatch,
+    Overflow,
+    ValueIsNotRepresentable(NumericConversionError),
+}
+
+impl fmt::Display for ExactEliminationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ExactEliminationError::MatrixIsNotSquare => {
+                write!(f, "Exact elimination failed because matrix is not square.")
+            },
+            ExactEliminationError::MatrixIsSingular => {
+                write!(f, "Exact LU Decomposition cannot be used because matrix is singular.")
+            },
+            ExactEliminationError::DimensionMismatch => {
+                write!(f, "Right hand side dimensions are not consistent with the decomposed matrix.")
+            },
+            ExactEliminationError::Overflow => {
+                write!(f, "Exact elimination failed because rational arithmetic overflowed.")
+            },
+            ExactEliminationError::ValueIsNotRepresentable(error) => {
+                write!(f, "Exact elimination failed because a value has no exact rational form: {}", error)
+            },
+        }
+    }
+}
+
+impl std::error::Error for ExactEliminationError {}
+
+impl From<NumericConversionError> for ExactEliminationError {
+    fn from(error: NumericConversionError) -> Self {
+        ExactEliminationError::ValueIsNotRepresentable(error)
+    }
+}
+
+impl From<RationalError> for ExactEliminationError {
+    fn from(error: RationalError) -> Self {
+        match error {
+            RationalError::ZeroDenominator => ExactEliminationError::MatrixIsSingular,
+            RationalError::Overflow => ExactEliminationError::Overflow,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ExactLuDecomposition {
+    lu: Matrix<Rational>,
+    permutation: Vec<usize>,
+    sign: Rational,
+    singular: bool,
+}
+
+pub trait ExactElimination {
+    fn exact_lu(&self) -> Result<ExactLuDecomposition, ExactEliminationError>;
+    fn exact_determinant(&self) -> Result<Rational, ExactEliminationError>;
+    fn exact_rank(&self) -> Result<usize, ExactEliminationError>;
+}
+
+// Entries must convert to rationals exactly, NaN, infinities and doubles that would round are rejected
+impl<T> ExactElimination for Matrix<T> where T: Real {
+    fn exact_lu(&self) -> Result<ExactLuDecomposition, ExactEliminationError> {
+        ExactLuDecomposition::decompose(self)
+    }
+
+    fn exact_determinant(&self) -> Result<Rational, ExactEliminationError> {
+        self.exact_lu()?.determinant()
+    }
+
+    fn exact_rank(&self) -> Result<usize, ExactEliminationError> {
+        let mut a = self.copy_to_checked::<Rational>()?;
+        let (m, n) = (a.rows(), a.cols());
+        let mut rank = 0;
+        for k in 0..n {
+            if rank == m { break }
+            let Some(p) = simplest_pivot(&a, k, rank) else { continue };
+            a.swap_rows(rank, p).unwrap();
+            eliminate(&mut a, rank, k)?;
+            rank += 1;
+        }
+        Ok(rank)
+    }
+}
+
+impl ExactLuDecomposition {
+
+    // Gaussian elimination over the rationals, P·A = L·U holds exactly
+    pub fn decompose<T>(m: &Matrix<T>) -> Result<Self, ExactEliminationError> where T: Real {
+        if !m.is_square() { return Err(ExactEliminationError::MatrixIsNotSquare) }
+        let n = m.rows();
+        let mut lu = m.copy_to_checked::<Rational>()?;
+        let mut permutation: Vec<usize> = (0..n).collect();
+        let mut sign = Rational::one();
+        let mut singular = false;
+        for k in 0..n {
+            let Some(p) = simplest_pivot(&lu, k, k) else {
+                singular = true;
+                continue
+            };
+            if p != k {
+                lu.swap_rows(k, p).unwrap();
+                permutation.swap(k, p);
+                sign = -sign;
+            }
+            eliminate(&mut lu, k, k)?;
+        }
+        Ok(ExactLuDecomposition { lu, permutation, sign, singular })
+    }
+
+    pub fn permutation(&self) -> &[usize] {
+        &self.permutation
+    }
+
+    pub fn is_singular(&self) -> bool {
+        self.singular
+    }
+
+    pub fn p(&self) -> Matrix<Rational> {
+        let n = self.permutation.len();
+        let mut p = MatrixBuilder::<Rational>::new().zeros(n, n).build().unwrap();
+        for (i, &j) in self.permutation.iter().enumerate() {
+            p[(i, j)] = Rational::one();
+        }
+        p
+    }
+
+    pub fn l(&self) -> Matrix<Rational> {
+        let n = self.lu.rows();
+        let mut l = MatrixBuilder::<Rational>::new().identity(n).build().unwrap();
+        for i in 0..n {
+            for j in 0..i {
+                l[(i, j)] = self.lu[(i, j)];
+            }
+        }
+        l
+    }
+
+    pub fn u(&self) -> Matrix<Rational> {
+        let n = self.lu.rows();
+        let mut u = MatrixBuilder::<Rational>::new().zeros(n, n).build().unwrap();
+        for i in 0..n {
+            for j in i..n {
+                u[(i, j)] = self.lu[(i, j)];
+            }
+        }
+        u
+    }
+
+    pub fn determinant(&self) -> Result<Rational, ExactEliminationError> {
+        if self.singular { return Ok(Rational::zero()) }
+        let mut det = self.sign;
+        for k in 0..self.lu.rows() {
+            det = det.checked_mul(&self.lu[(k, k)])?;
+        }
+        Ok(det)
+    }
+
+    pub fn solve<T>(&self, b: &Matrix<T>) -> Result<Matrix<Rational>, ExactEliminationError> where T: Real {
+        if self.singular { return Err(ExactEliminationError::MatrixIsSingular) }
+        let n = self.lu.rows();
+        if b.rows() != n { return Err(ExactEliminationError::DimensionMismatch) }
+        let mut x = MatrixBuilder::<Rational>::new().zeros(n, b.cols()).build().unwrap();
+        for (i, &p) in self.permutation.iter().enumerate() {
+            for j in 0..b.cols() {
+                x[(i, j)] = b[(p, j)].try_to::<Rational>()
+                    .map_err(|error| NumericConversionError { position: Some((p, j)), ..error })?;
+            }
+        }
+        for j in 0..x.cols() {
+            for i in 0..n {
+                let mut sum = x[(i, j)];
+                for k in 0..i {
+                    sum = sum.checked_sub(&self.lu[(i, k)].checked_mul(&x[(k, j)])?)?;
+                }
+                x[(i, j)] = sum;
+            }
+            for i in (0..n).rev() {
+                let mut sum = x[(i, j)];
+                for k in (i + 1)..n {
+                    sum = sum.checked_sub(&self.lu[(i, k)].checked_mul(&x[(k, j)])?)?;
+                }
+                x[(i, j)] = sum.checked_div(&self.lu[(i, i)])?;
+            }
+        }
+        Ok(x)
+    }
+
+    pub fn inverse(&self) -> Result<Matrix<Rational>, ExactEliminationError> {
+        self.solve(&MatrixBuilder::<Rational>::new().identity(self.lu.rows()).build().unwrap())
+    }
+
+}
+
+// Any non zero pivot is exact, the one with the smallest terms limits the growth of later entries
+fn simplest_pivot(a: &Matrix<Rational>, col: usize, from: usize) -> Option<usize> {
+    (from..a.rows())
+        .filter(|&i| !a[(i, col)].is_zero())
+        .min_by_key(|&i| a[(i, col)].numerator().unsigned_abs().max(a[(i, col)].denominator().unsigned_abs()))
+}
+
+// Eliminates column col below row k, storing the multipliers in place of the zeroed entries
+fn eliminate(a: &mut Matrix<Rational>, k: usize, col: usize) -> Result<(), ExactEliminationError> {
+    for i in (k + 1)..a.rows() {
+        if a[(i, col)].is_zero() { continue }
+        let factor = a[(i, col)].checked_div(&a[(k, col)])?;
+        a[(i, col)] = factor;
+        for j in (col + 1)..a.cols() {
+            a[(i, j)] = a[(i, j)].checked_sub(&factor.checked_mul(&a[(k, j)])?)?;
+        }
+    }
+    Ok(())
+}
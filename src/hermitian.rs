@@ -0,0 +1,164 @@
+use crate::numeric::Float;
+use crate::complex::Complex;
+use crate::matrix::{ Matrix, MatrixBuilder };
+use crate::square::Square;
+use crate::view::{ MatrixView, MatrixViewMut };
+use crate::tolerance::Tolerance;
+use crate::definite::CholeskyDecompositionError;
+
+impl<F> Matrix<Complex<F>> where F: Float {
+    pub fn conjugate(&self) -> Matrix<Complex<F>> {
+        Matrix::from_parts(self.rows(), self.cols(), self.data().iter().map(|z| z.conj()).collect())
+    }
+
+    pub fn conjugate_transpose(&self) -> Matrix<Complex<F>> {
+        self.transpose().conjugate()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct HermitianCholeskyFactor<F> where F: Float {
+    l: Matrix<Complex<F>>,
+}
+
+impl<F> HermitianCholeskyFactor<F> where F: Float {
+
+    // Lower triangular with a real positive diagonal, A = L·Lᴴ
+    pub fn l(&self) -> &Matrix<Complex<F>> {
+        &self.l
+    }
+
+    pub fn into_l(self) -> Matrix<Complex<F>> {
+        self.l
+    }
+
+    pub fn dim(&self) -> usize {
+        self.l.rows()
+    }
+
+    // Solves A·X = B through L·Y = B then Lᴴ·X = Y
+    pub fn solve(&self, b: &Matrix<Complex<F>>) -> Result<Matrix<Complex<F>>, CholeskyDecompositionError> {
+        let n = self.dim();
+        if b.rows() != n { return Err(CholeskyDecompositionError::DimensionMismatch) }
+        let mut x = b.clone();
+        for j in 0..x.cols() {
+            for i in 0..n {
+                let mut sum = x[(i, j)];
+                for k in 0..i {
+                    sum = sum - self.l[(i, k)] * x[(k, j)];
+                }
+                x[(i, j)] = sum / self.l[(i, i)];
+            }
+            for i in (0..n).rev() {
+                let mut sum = x[(i, j)];
+                for k in (i + 1)..n {
+                    sum = sum - self.l[(k, i)].conj() * x[(k, j)];
+                }
+                x[(i, j)] = sum / self.l[(i, i)];
+            }
+        }
+        Ok(x)
+    }
+
+    // Real since A is Hermitian positive definite
+    pub fn determinant(&self) -> F {
+        (0..self.dim()).fold(F::one(), |det, k| det * self.l[(k, k)].norm_sqr())
+    }
+
+}
+
+pub trait Hermitian<F> where F: Float {
+    fn is_hermitian(&self) -> bool;
+    fn is_hermitian_within(&self, tolerance: Tolerance) -> bool;
+    fn is_hermitian_positive_definite(&self) -> bool;
+    fn hermitian_cholesky(&self) -> Result<HermitianCholeskyFactor<F>, CholeskyDecompositionError>;
+}
+
+impl<F> Hermitian<F> for Matrix<Complex<F>> where F: Float {
+    fn is_hermitian(&self) -> bool {
+        self.view().is_hermitian()
+    }
+
+    fn is_hermitian_within(&self, tolerance: Tolerance) -> bool {
+        self.view().is_hermitian_within(tolerance)
+    }
+
+    fn is_hermitian_positive_definite(&self) -> bool {
+        self.view().is_hermitian_positive_definite()
+    }
+
+    fn hermitian_cholesky(&self) -> Result<HermitianCholeskyFactor<F>, CholeskyDecompositionError> {
+        self.view().hermitian_cholesky()
+    }
+}
+
+impl<F> Hermitian<F> for MatrixView<'_, Complex<F>> where F: Float {
+    fn is_hermitian(&self) -> bool {
+        if !self.is_square() { return false }
+        for i in 0..self.rows() {
+            for j in 0..=i {
+                if self[(i, j)] != self[(j, i)].conj() { return false }
+            }
+        }
+        true
+    }
+
+    fn is_hermitian_within(&self, tolerance: Tolerance) -> bool {
+        if !self.is_square() { return false }
+        for i in 0..self.rows() {
+            for j in 0..=i {
+                let (a, b) = (self[(i, j)], self[(j, i)].conj());
+                if !tolerance.is_within(a.re.to::<f64>(), b.re.to::<f64>()) { return false }
+                if !tolerance.is_within(a.im.to::<f64>(), b.im.to::<f64>()) { return false }
+            }
+        }
+        true
+    }
+
+    fn is_hermitian_positive_definite(&self) -> bool {
+        self.hermitian_cholesky().is_ok()
+    }
+
+    fn hermitian_cholesky(&self) -> Result<HermitianCholeskyFactor<F>, CholeskyDecompositionError> {
+        if !self.is_hermitian() { return Err(CholeskyDecompositionError::MatrixIsNotPositiveDefinite) }
+        let n = self.rows();
+        let mut l = MatrixBuilder::<Complex<F>>::new().zeros(n, n).build().unwrap();
+        for j in 0..n {
+            let mut pivot = self[(j, j)].re;
+            for k in 0..j {
+                pivot -= l[(j, k)].norm_sqr();
+            }
+            if pivot.is_nan() || pivot <= F::zero() {
+                return Err(CholeskyDecompositionError::LeadingMinorIsNotPositive { index: j, pivot: pivot.to::<f64>() })
+            }
+            let l_jj = pivot.sqrt();
+            l[(j, j)] = Complex::new(l_jj, F::zero());
+            for i in (j + 1)..n {
+                let mut sum = self[(i, j)];
+                for k in 0..j {
+                    sum = sum - l[(i, k)] * l[(j, k)].conj();
+                }
+                l[(i, j)] = Complex::new(sum.re / l_jj, sum.im / l_jj);
+            }
+        }
+        Ok(HermitianCholeskyFactor { l })
+    }
+}
+
+impl<F> Hermitian<F> for MatrixViewMut<'_, Complex<F>> where F: Float {
+    fn is_hermitian(&self) -> bool {
+        self.as_view().is_hermitian()
+    }
+
+    fn is_hermitian_within(&self, tolerance: Tolerance) -> bool {
+        self.as_view().is_hermitian_within(tolerance)
+    }
+
+    fn is_hermitian_positive_definite(&self) -> bool {
+        self.as_view().is_hermitian_positive_definite()
+    }
+
+    fn hermitian_cholesky(&self) -> Result<HermitianCholeskyFactor<F>, CholeskyDecompositionError> {
+        self.as_view().hermitian_cholesky()
+    }
+}
@@ -0,0 +1,235 @@
+use std::fmt;
+
+use crate::numeric::Real;
+use crate::matrix::{ Matrix, MatrixBuilder };
+use crate::symmetric::Symmetric;
+use crate::triangular::{ solve_lower_in_place, solve_lower_transpose_in_place };
+
+#[derive(Debug, PartialEq)]
+pub enum LdltDecompositionError {
+    MatrixIsNotSymmetric,
+    MatrixIsSingular,
+    DimensionMismatch,
+}
+
+impl fmt::Display for LdltDecompositionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            LdltDecompositionError::MatrixIsNotSymmetric => {
+                write!(f, "LDLT Decomposition failed because matrix is not symmetric.")
+            },
+            LdltDecompositionError::MatrixIsSingular => {
+                write!(f, "LDLT Decomposition cannot be used because matrix is singular.")
+            },
+            LdltDecompositionError::DimensionMismatch => {
+                write!(f, "Right hand side dimensions are not consistent with the decomposed matrix.")
+            },
+        }
+    }
+}
+
+impl std::error::Error for LdltDecompositionError {}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Inertia {
+    pub positive: usize,
+    pub negative: usize,
+    pub zero: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct LdltDecomposition {
+    l: Matrix<f64>,
+    d: Matrix<f64>,
+    permutation: Vec<usize>,
+    block_sizes: Vec<usize>,
+    tolerance: f64,
+}
+
+pub trait SymmetricIndefinite {
+    fn ldlt(&self) -> Result<LdltDecomposition, LdltDecompositionError>;
+}
+
+impl<T> SymmetricIndefinite for Matrix<T> where T: Real {
+    fn ldlt(&self) -> Result<LdltDecomposition, LdltDecompositionError> {
+        if !self.is_symmetric() { return Err(LdltDecompositionError::MatrixIsNotSymmetric) }
+        Ok(LdltDecomposition::decompose(self.copy_to::<f64>().unwrap()))
+    }
+}
+
+impl LdltDecomposition {
+
+    // Bunch-Kaufman diagonal pivoting, P·A·Pᵀ = L·D·Lᵀ with 1x1 and 2x2 blocks in D
+    fn decompose(mut a: Matrix<f64>) -> Self {
+        let n = a.rows();
+        let alpha = (1f64 + 17f64.sqrt()) / 8f64;
+        let scale = a.data().iter().fold(0f64, |acc, x| acc.max(x.abs()));
+        let tolerance = scale * f64::EPSILON * n as f64;
+        let mut l = MatrixBuilder::<f64>::new().identity(n).build().unwrap();
+        let mut d = MatrixBuilder::<f64>::new().zeros(n, n).build().unwrap();
+        let mut permutation: Vec<usize> = (0..n).collect();
+        let mut block_sizes = Vec::new();
+        let mut k = 0;
+        while k < n {
+            let absakk = a[(k, k)].abs();
+            let (r, colmax) = ((k + 1)..n)
+                .map(|i| (i, a[(i, k)].abs()))
+                .fold((k, 0f64), |acc, (i, x)| if x > acc.1 { (i, x) } else { acc });
+            let mut size = 1;
+            if absakk.max(colmax) > 0f64 && absakk < alpha * colmax {
+                let rowmax = (k..n).filter(|&j| j != r).fold(0f64, |acc, j| acc.max(a[(r, j)].abs()));
+                if absakk >= alpha * colmax * (colmax / rowmax) {
+                    // 1x1 pivot at k
+                } else if a[(r, r)].abs() >= alpha * rowmax {
+                    symmetric_swap(&mut a, &mut l, &mut permutation, k, r);
+                } else {
+                    symmetric_swap(&mut a, &mut l, &mut permutation, k + 1, r);
+                    size = 2;
+                }
+            }
+            if size == 1 {
+                let pivot = a[(k, k)];
+                d[(k, k)] = pivot;
+                if pivot != 0f64 {
+                    for i in (k + 1)..n { l[(i, k)] = a[(i, k)] / pivot; }
+                    for i in (k + 1)..n {
+                        for j in (k + 1)..=i {
+                            a[(i, j)] -= l[(i, k)] * a[(j, k)];
+                            a[(j, i)] = a[(i, j)];
+                        }
+                    }
+                }
+            } else {
+                let (p, q, s) = (a[(k, k)], a[(k + 1, k)], a[(k + 1, k + 1)]);
+                let det = p * s - q * q;
+                d[(k, k)] = p;
+                d[(k + 1, k)] = q;
+                d[(k, k + 1)] = q;
+                d[(k + 1, k + 1)] = s;
+                for i in (k + 2)..n {
+                    let (x, y) = (a[(i, k)], a[(i, k + 1)]);
+                    l[(i, k)] = (x * s - y * q) / det;
+                    l[(i, k + 1)] = (y * p - x * q) / det;
+                }
+                for i in (k + 2)..n {
+                    for j in (k + 2)..=i {
+                        a[(i, j)] -= l[(i, k)] * a[(j, k)] + l[(i, k + 1)] * a[(j, k + 1)];
+                        a[(j, i)] = a[(i, j)];
+                    }
+                }
+            }
+            block_sizes.push(size);
+            k += size;
+        }
+        LdltDecomposition { l, d, permutation, block_sizes, tolerance }
+    }
+
+    pub fn l(&self) -> &Matrix<f64> {
+        &self.l
+    }
+
+    pub fn d(&self) -> &Matrix<f64> {
+        &self.d
+    }
+
+    pub fn permutation(&self) -> &[usize] {
+        &self.permutation
+    }
+
+    pub fn block_sizes(&self) -> &[usize] {
+        &self.block_sizes
+    }
+
+    // Sylvester's law of inertia: A and D share the signs of their eigen values
+    pub fn inertia(&self) -> Inertia {
+        let mut inertia = Inertia { positive: 0, negative: 0, zero: 0 };
+        let mut count = |value: f64| {
+            if value.abs() <= self.tolerance { inertia.zero += 1 }
+            else if value > 0f64 { inertia.positive += 1 }
+            else { inertia.negative += 1 }
+        };
+        let mut k = 0;
+        for &size in &self.block_sizes {
+            if size == 1 {
+                count(self.d[(k, k)]);
+            } else {
+                let (p, q, s) = (self.d[(k, k)], self.d[(k + 1, k)], self.d[(k + 1, k + 1)]);
+                let mean = (p + s) / 2f64;
+                let radius = ((p - s) / 2f64).hypot(q);
+                count(mean + radius);
+                count(mean - radius);
+            }
+            k += size;
+        }
+        inertia
+    }
+
+    pub fn determinant(&self) -> f64 {
+        let mut det = 1f64;
+        let mut k = 0;
+        for &size in &self.block_sizes {
+            det *= if size == 1 {
+                self.d[(k, k)]
+            } else {
+                self.d[(k, k)] * self.d[(k + 1, k + 1)] - self.d[(k + 1, k)] * self.d[(k + 1, k)]
+            };
+            k += size;
+        }
+        det
+    }
+
+    pub fn solve<T>(&self, b: &Matrix<T>) -> Result<Matrix<f64>, LdltDecompositionError> where T: Real {
+        let n = self.l.rows();
+        if b.rows() != n { return Err(LdltDecompositionError::DimensionMismatch) }
+        if self.inertia().zero > 0 { return Err(LdltDecompositionError::MatrixIsSingular) }
+        let mut y = MatrixBuilder::<f64>::new().zeros(n, b.cols()).build().unwrap();
+        for (i, &p) in self.permutation.iter().enumerate() {
+            for j in 0..b.cols() { y[(i, j)] = b[(p, j)].to::<f64>(); }
+        }
+        solve_lower_in_place(&self.l, &mut y);
+        let mut k = 0;
+        for &size in &self.block_sizes {
+            for j in 0..y.cols() {
+                if size == 1 {
+                    y[(k, j)] /= self.d[(k, k)];
+                } else {
+                    let (p, q, s) = (self.d[(k, k)], self.d[(k + 1, k)], self.d[(k + 1, k + 1)]);
+                    let det = p * s - q * q;
+                    let (u, v) = (y[(k, j)], y[(k + 1, j)]);
+                    y[(k, j)] = (u * s - v * q) / det;
+                    y[(k + 1, j)] = (v * p - u * q) / det;
+                }
+            }
+            k += size;
+        }
+        solve_lower_transpose_in_place(&self.l, &mut y);
+        let mut x = MatrixBuilder::<f64>::new().zeros(n, b.cols()).build().unwrap();
+        for (i, &p) in self.permutation.iter().enumerate() {
+            for j in 0..b.cols() { x[(p, j)] = y[(i, j)]; }
+        }
+        Ok(x)
+    }
+
+}
+
+// Swaps rows and columns i and j of the trailing matrix along with the computed rows of L
+fn symmetric_swap(a: &mut Matrix<f64>, l: &mut Matrix<f64>, permutation: &mut [usize], i: usize, j: usize) {
+    if i == j { return }
+    let n = a.rows();
+    for c in 0..n {
+        let x = a[(i, c)];
+        a[(i, c)] = a[(j, c)];
+        a[(j, c)] = x;
+    }
+    for r in 0..n {
+        let x = a[(r, i)];
+        a[(r, i)] = a[(r, j)];
+        a[(r, j)] = x;
+    }
+    for c in 0..i.min(j) {
+        let x = l[(i, c)];
+        l[(i, c)] = l[(j, c)];
+        l[(j, c)] = x;
+    }
+    permutation.swap(i, j);
+}
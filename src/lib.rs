@@ -1,8 +1,81 @@
 mod numeric;
+mod complex;
+mod rational;
 mod matrix;
+mod arithmetic;
+mod view;
+mod tolerance;
+mod approx;
 mod square;
 mod symmetric;
 mod definite;
+mod indefinite;
+mod lu;
+mod triangular;
+mod qr;
+mod svd;
+mod schur;
+mod correlation;
+mod hermitian;
+mod exact;
+#[cfg(feature = "parallel")]
+mod parallel;
+
+pub use numeric::{ Numeric, Real, Float, ThreadSafe, NumericConversionError };
+pub use complex::Complex;
+pub use rational::{ Rational, RationalError };
+pub use matrix::{ Matrix, MatrixBuilder, Swap, MatrixDefinitionError, MatrixIndexOutOfBoundsError, MatrixCopyToError };
+pub use arithmetic::{ MatrixDimensionMismatchError, MatrixArithmeticError, CheckedArithmetic };
+pub use view::{ MatrixView, MatrixViewMut };
+pub use tolerance::Tolerance;
+pub use approx::ElementDifference;
+#[doc(hidden)]
+pub use approx::approx_eq_failure;
+pub use square::Square;
+pub use symmetric::{ Symmetric, Algorithm, EigenDecomposition, EigenDecompositionError, JacobiDecomposition, TridiagonalQLDecomposition };
+pub use definite::{ PositiveDefinite, PositiveSemiDefinite, CholeskyDecompositionError, CholeskyFactor, ModifiedCholeskyFactor, PivotedCholeskyFactor };
+pub use indefinite::{ SymmetricIndefinite, LdltDecomposition, LdltDecompositionError, Inertia };
+pub use lu::{ Lu, LuDecomposition, LuDecompositionError };
+pub use triangular::{ Triangular, TriangularSolveError };
+pub use qr::{ Qr, QrDecomposition, QrDecompositionError, least_squares };
+pub use svd::{ Svd, SingularValueDecomposition, SingularValueDecompositionError };
+pub use schur::{ GeneralEigen, GeneralEigenDecomposition, RealSchurDecomposition };
+pub use correlation::{ NearestCorrelation, NearestCorrelationError, CorrelationRepair };
+pub use hermitian::{ Hermitian, HermitianCholeskyFactor };
+pub use exact::{ ExactElimination, ExactLuDecomposition, ExactEliminationError };
+#[cfg(feature = "parallel")]
+pub use parallel::{ set_threads, threads };
+
+// Threads the operations may split their work across, the value set with set_threads
+#[cfg(feature = "parallel")]
+pub(crate) fn worker_threads() -> usize {
+    parallel::threads()
+}
+
+#[cfg(not(feature = "parallel"))]
+pub(crate) fn worker_threads() -> usize {
+    1
+}
+
+pub mod prelude {
+    pub use crate::numeric::{ Numeric, Real, Float };
+    pub use crate::matrix::{ Matrix, MatrixBuilder, Swap };
+    pub use crate::arithmetic::CheckedArithmetic;
+    pub use crate::view::{ MatrixView, MatrixViewMut };
+    pub use crate::tolerance::Tolerance;
+    pub use crate::square::Square;
+    pub use crate::symmetric::{ Symmetric, Algorithm };
+    pub use crate::definite::{ PositiveDefinite, PositiveSemiDefinite };
+    pub use crate::indefinite::SymmetricIndefinite;
+    pub use crate::lu::Lu;
+    pub use crate::triangular::Triangular;
+    pub use crate::qr::Qr;
+    pub use crate::svd::Svd;
+    pub use crate::schur::GeneralEigen;
+    pub use crate::correlation::NearestCorrelation;
+    pub use crate::hermitian::Hermitian;
+    pub use crate::exact::ExactElimination;
+}
 
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,167 @@
+use std::fmt;
+
+use crate::numeric::Real;
+use crate::matrix::{ Matrix, MatrixBuilder, Swap };
+use crate::square::Square;
+
+#[derive(Debug, PartialEq)]
+pub enum LuDecompositionError {
+    MatrixIsNotSquare,
+    MatrixIsSingular,
+    DimensionMismatch,
+}
+
+impl fmt::Display for LuDecompositionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            LuDecompositionError::MatrixIsNotSquare => {
+                write!(f, "LU Decomposition failed because matrix is not square.")
+            },
+            LuDecompositionError::MatrixIsSingular => {
+                write!(f, "LU Decomposition cannot be used because matrix is singular.")
+            },
+            LuDecompositionError::DimensionMismatch => {
+                write!(f, "Right hand side dimensions are not consistent with the decomposed matrix.")
+            },
+        }
+    }
+}
+
+impl std::error::Error for LuDecompositionError {}
+
+#[derive(Debug, Clone)]
+pub struct LuDecomposition {
+    lu: Matrix<f64>,
+    permutation: Vec<usize>,
+    sign: f64,
+    singular: bool,
+}
+
+pub trait Lu {
+    fn lu(&self) -> Result<LuDecomposition, LuDecompositionError>;
+}
+
+impl<T> Lu for Matrix<T> where T: Real {
+    fn lu(&self) -> Result<LuDecomposition, LuDecompositionError> {
+        LuDecomposition::decompose(self)
+    }
+}
+
+impl LuDecomposition {
+
+    // Doolittle elimination with partial pivoting, P·A = L·U stored in a single matrix
+    pub fn decompose<T>(m: &Matrix<T>) -> Result<Self, LuDecompositionError> where T: Real {
+        if !m.is_square() { return Err(LuDecompositionError::MatrixIsNotSquare) }
+        let n = m.rows();
+        let mut lu = m.copy_to::<f64>().unwrap();
+        let mut permutation: Vec<usize> = (0..n).collect();
+        let mut sign = 1f64;
+        let mut singular = false;
+        let scale = lu.data().iter().fold(0f64, |acc, a| acc.max(a.abs()));
+        let tolerance = scale * f64::EPSILON * n as f64;
+        for k in 0..n {
+            let mut p = k;
+            let mut pivot = lu[(k, k)].abs();
+            for i in (k + 1)..n {
+                if lu[(i, k)].abs() > pivot {
+                    p = i;
+                    pivot = lu[(i, k)].abs();
+                }
+            }
+            if p != k {
+                lu.swap_rows(k, p).unwrap();
+                permutation.swap(k, p);
+                sign = -sign;
+            }
+            if pivot <= tolerance {
+                singular = true;
+                if pivot == 0f64 { continue }
+            }
+            for i in (k + 1)..n {
+                let factor = lu[(i, k)] / lu[(k, k)];
+                lu[(i, k)] = factor;
+                for j in (k + 1)..n {
+                    lu[(i, j)] -= factor * lu[(k, j)];
+                }
+            }
+        }
+        Ok(LuDecomposition { lu, permutation, sign, singular })
+    }
+
+    pub fn permutation(&self) -> &[usize] {
+        &self.permutation
+    }
+
+    pub fn is_singular(&self) -> bool {
+        self.singular
+    }
+
+    pub fn p(&self) -> Matrix<f64> {
+        let n = self.permutation.len();
+        let mut p = MatrixBuilder::<f64>::new().zeros(n, n).build().unwrap();
+        for (i, &j) in self.permutation.iter().enumerate() {
+            p[(i, j)] = 1f64;
+        }
+        p
+    }
+
+    pub fn l(&self) -> Matrix<f64> {
+        let n = self.lu.rows();
+        let mut l = MatrixBuilder::<f64>::new().identity(n).build().unwrap();
+        for i in 0..n {
+            for j in 0..i {
+                l[(i, j)] = self.lu[(i, j)];
+            }
+        }
+        l
+    }
+
+    pub fn u(&self) -> Matrix<f64> {
+        let n = self.lu.rows();
+        let mut u = MatrixBuilder::<f64>::new().zeros(n, n).build().unwrap();
+        for i in 0..n {
+            for j in i..n {
+                u[(i, j)] = self.lu[(i, j)];
+            }
+        }
+        u
+    }
+
+    pub fn determinant(&self) -> f64 {
+        (0..self.lu.rows()).fold(self.sign, |det, k| det * self.lu[(k, k)])
+    }
+
+    pub fn solve<T>(&self, b: &Matrix<T>) -> Result<Matrix<f64>, LuDecompositionError> where T: Real {
+        if self.singular { return Err(LuDecompositionError::MatrixIsSingular) }
+        let n = self.lu.rows();
+        if b.rows() != n { return Err(LuDecompositionError::DimensionMismatch) }
+        let mut x = MatrixBuilder::<f64>::new().zeros(n, b.cols()).build().unwrap();
+        for (i, &p) in self.permutation.iter().enumerate() {
+            for j in 0..b.cols() {
+                x[(i, j)] = b[(p, j)].to::<f64>();
+            }
+        }
+        for j in 0..x.cols() {
+            for i in 0..n {
+                let mut sum = x[(i, j)];
+                for k in 0..i {
+                    sum -= self.lu[(i, k)] * x[(k, j)];
+                }
+                x[(i, j)] = sum;
+            }
+            for i in (0..n).rev() {
+                let mut sum = x[(i, j)];
+                for k in (i + 1)..n {
+                    sum -= self.lu[(i, k)] * x[(k, j)];
+                }
+                x[(i, j)] = sum / self.lu[(i, i)];
+            }
+        }
+        Ok(x)
+    }
+
+    pub fn inverse(&self) -> Result<Matrix<f64>, LuDecompositionError> {
+        self.solve(&MatrixBuilder::<f64>::new().identity(self.lu.rows()).build().unwrap())
+    }
+
+}
@@ -1,7 +1,8 @@
 use std::ops;
 use std::fmt;
+use std::ops::{ Bound, RangeBounds };
 
-use crate::numeric::Numeric;
+use crate::numeric::{ Numeric, NumericConversionError };
 
 #[derive(Debug)]
 pub enum MatrixDefinitionError {
@@ -61,7 +62,7 @@ impl fmt::Display for MatrixCopyToError {
 
 impl std::error::Error for MatrixCopyToError {}
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Matrix<T> where T: Numeric{
     rows: usize,
     cols: usize,
@@ -82,6 +83,15 @@ impl<T> Matrix<T> where T: Numeric {
         &self.data
     }
 
+    pub fn data_mut(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+
+    pub(crate) fn from_parts(rows: usize, cols: usize, data: Vec<T>) -> Self {
+        debug_assert_eq!(data.len(), rows * cols);
+        Matrix { rows, cols, data }
+    }
+
     pub fn copy_to<S>(&self) -> Result<Matrix<S>, MatrixCopyToError> where S: Numeric {
         MatrixBuilder::<S>::new()
         .from_vec(
@@ -93,6 +103,96 @@ impl<T> Matrix<T> where T: Numeric {
         .map_err(MatrixCopyToError::DefinitionError)
     }
 
+    // Fails on the first element the target type cannot represent instead of wrapping or saturating
+    pub fn copy_to_checked<S>(&self) -> Result<Matrix<S>, NumericConversionError> where S: Numeric {
+        let mut data = Vec::with_capacity(self.data.len());
+        for (k, element) in self.data.iter().enumerate() {
+            data.push(element.try_to::<S>().map_err(|error| NumericConversionError {
+                position: Some((k / self.cols, k % self.cols)),
+                ..error
+            })?);
+        }
+        Ok(Matrix { rows: self.rows, cols: self.cols, data })
+    }
+
+    pub fn transpose(&self) -> Matrix<T> {
+        let mut data = Vec::with_capacity(self.data.len());
+        for j in 0..self.cols {
+            for i in 0..self.rows {
+                data.push(self.data[i * self.cols + j]);
+            }
+        }
+        Matrix { rows: self.cols, cols: self.rows, data }
+    }
+
+    pub fn transpose_in_place(&mut self) {
+        let (rows, cols) = (self.rows, self.cols);
+        if rows == cols {
+            for i in 0..rows {
+                for j in (i + 1)..cols {
+                    self.data.swap(i * cols + j, j * cols + i);
+                }
+            }
+        } else {
+            // Follow the cycles of the permutation sending n = i * cols + j to j * rows + i
+            let last = rows * cols - 1;
+            let mut visited = vec![false; rows * cols];
+            for start in 1..last {
+                if visited[start] { continue }
+                let mut n = start;
+                loop {
+                    let next = (n * rows) % last;
+                    visited[n] = true;
+                    if next == start { break }
+                    self.data.swap(start, next);
+                    n = next;
+                }
+            }
+        }
+        self.rows = cols;
+        self.cols = rows;
+    }
+
+    pub fn row(&self, i: usize) -> Result<&[T], MatrixIndexOutOfBoundsError> {
+        if i >= self.rows { return Err(MatrixIndexOutOfBoundsError) }
+        Ok(&self.data[i * self.cols..(i + 1) * self.cols])
+    }
+
+    pub fn col(&self, j: usize) -> Result<Vec<T>, MatrixIndexOutOfBoundsError> {
+        if j >= self.cols { return Err(MatrixIndexOutOfBoundsError) }
+        Ok((0..self.rows).map(|i| self.data[i * self.cols + j]).collect())
+    }
+
+    pub fn submatrix<R, C>(&self, row_range: R, col_range: C) -> Result<Matrix<T>, MatrixIndexOutOfBoundsError>
+    where R: RangeBounds<usize>, C: RangeBounds<usize> {
+        let (row_start, row_end) = resolve_range(&row_range, self.rows)?;
+        let (col_start, col_end) = resolve_range(&col_range, self.cols)?;
+        let mut data = Vec::with_capacity((row_end - row_start) * (col_end - col_start));
+        for i in row_start..row_end {
+            data.extend_from_slice(&self.data[i * self.cols + col_start..i * self.cols + col_end]);
+        }
+        Ok(Matrix { rows: row_end - row_start, cols: col_end - col_start, data })
+    }
+
+}
+
+pub(crate) fn resolve_range<R>(range: &R, len: usize) -> Result<(usize, usize), MatrixIndexOutOfBoundsError> 
+where R: RangeBounds<usize> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start + 1,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end + 1,
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+    if start >= end || end > len {
+        Err(MatrixIndexOutOfBoundsError)
+    } else {
+        Ok((start, end))
+    }
 }
 
 impl<T> ops::Index<(usize, usize)> for Matrix<T> where T: Numeric {
@@ -179,6 +279,12 @@ pub struct MatrixBuilder<T> {
     data: Option<Vec<T>>,
 }
 
+impl<T> Default for MatrixBuilder<T> where T: Numeric {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<T> MatrixBuilder<T> where T: Numeric {
     pub fn new() -> Self {
         MatrixBuilder {
@@ -208,7 +314,6 @@ impl<T> MatrixBuilder<T> where T: Numeric {
     }
 
     pub fn from_mat(self, data: Vec<Vec<T>>) -> Self {
-        println!("data = {:?}", data);
         if let Some(first_row) = data.first() {
             let rows = data.len();
             let cols = first_row.len();
@@ -1,5 +1,9 @@
 use std::ops;
 use std::cmp;
+use std::fmt;
+
+use crate::complex::Complex;
+use crate::rational::Rational;
 
 pub trait Numeric: 
 std::fmt::Debug +
@@ -17,79 +21,249 @@ ops::Div<Output = Self> {
     fn from_f32(value: f32) -> Self;
     fn from_f64(value: f64) -> Self;
     fn from_usize(value: usize) -> Self;
+    // Wide integers go through i64 when they fit, through f64 otherwise
+    fn from_i128(value: i128) -> Self {
+        match i64::try_from(value) {
+            Ok(value) => Self::from_i64(value),
+            Err(_) => Self::from_f64(value as f64),
+        }
+    }
+    fn from_u128(value: u128) -> Self {
+        match i128::try_from(value) {
+            Ok(value) => Self::from_i128(value),
+            Err(_) => Self::from_f64(value as f64),
+        }
+    }
+    // Keeps the real part like an `as` cast keeps the low bits, try_from_complex rejects a non zero imaginary part
+    fn from_complex(value: Complex<f64>) -> Self {
+        Self::from_f64(value.re)
+    }
+    fn from_rational(value: Rational) -> Self {
+        value.convert()
+    }
     fn to<S>(&self) -> S where S: Numeric;
-}
-
-impl Numeric for i32 {
-    fn one() -> Self {
-        1i32
+    // Binary digits of the significand, the precision ULP tolerances are counted at
+    fn significand_digits() -> u32 {
+        f64::MANTISSA_DIGITS
     }
 
-    fn zero() -> Self {
-        0i32
+    // Fallible counterparts of the conversion hub, None when the value is out of the target range
+    fn try_from_i64(value: i64) -> Option<Self> {
+        Some(Self::from_i64(value))
     }
-
-    fn from_i32(value: i32) -> Self {
-        value
+    fn try_from_i128(value: i128) -> Option<Self> {
+        match i64::try_from(value) {
+            Ok(value) => Self::try_from_i64(value),
+            Err(_) => Self::try_from_f64(value as f64),
+        }
     }
-
-    fn from_i64(value: i64) -> Self {
-        value as i32
+    fn try_from_u128(value: u128) -> Option<Self> {
+        match i128::try_from(value) {
+            Ok(value) => Self::try_from_i128(value),
+            Err(_) => Self::try_from_f64(value as f64),
+        }
     }
-
-    fn from_usize(value: usize) -> Self {
-        value as i32
+    fn try_from_f64(value: f64) -> Option<Self> {
+        Some(Self::from_f64(value))
     }
-
-    fn from_f32(value: f32) -> Self {
-        value.trunc() as i32
+    fn try_from_complex(value: Complex<f64>) -> Option<Self> {
+        if value.im == 0f64 { Self::try_from_f64(value.re) } else { None }
     }
+    fn try_from_rational(value: Rational) -> Option<Self> {
+        value.try_convert()
+    }
+    fn try_to<S>(&self) -> Result<S, NumericConversionError> where S: Numeric;
+}
 
-    fn from_f64(value: f64) -> Self {
-        value.trunc() as i32
+// Bound of the operations the parallel feature splits across threads, every type satisfies it without the feature
+#[cfg(feature = "parallel")]
+pub trait ThreadSafe: Send + Sync {}
+#[cfg(feature = "parallel")]
+impl<T> ThreadSafe for T where T: Send + Sync {}
+#[cfg(not(feature = "parallel"))]
+pub trait ThreadSafe {}
+#[cfg(not(feature = "parallel"))]
+impl<T> ThreadSafe for T {}
+
+#[derive(Debug, PartialEq)]
+pub struct NumericConversionError {
+    pub value: String,
+    pub position: Option<(usize, usize)>,
+}
+
+impl NumericConversionError {
+    pub(crate) fn new<T>(value: &T) -> Self where T: Numeric {
+        NumericConversionError { value: value.to_string(), position: None }
     }
+}
 
-    fn to<S> (&self) -> S where S: Numeric {
-        S::from_i32(*self)
+impl fmt::Display for NumericConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.position {
+            Some((i, j)) => write!(f, "Value {} at ({}, {}) cannot be represented in the target type", self.value, i, j),
+            None => write!(f, "Value {} cannot be represented in the target type", self.value),
+        }
     }
+}
+
+impl std::error::Error for NumericConversionError {}
+
+// Every integer type converts exactly into i128 or u128, so those two entries of the hub never lose precision
+macro_rules! impl_signed_numeric {
+    ($t:ty, $entry:ident, $hub:ty) => {
+        impl Numeric for $t {
+            fn one() -> Self {
+                1
+            }
+
+            fn zero() -> Self {
+                0
+            }
+
+            fn from_i32(value: i32) -> Self {
+                value as $t
+            }
+
+            fn from_i64(value: i64) -> Self {
+                value as $t
+            }
+
+            fn from_i128(value: i128) -> Self {
+                value as $t
+            }
+
+            fn from_u128(value: u128) -> Self {
+                value as $t
+            }
 
+            fn from_usize(value: usize) -> Self {
+                value as $t
+            }
+
+            fn from_f32(value: f32) -> Self {
+                value.trunc() as $t
+            }
+
+            fn from_f64(value: f64) -> Self {
+                value.trunc() as $t
+            }
+
+            fn try_from_i64(value: i64) -> Option<Self> {
+                <$t>::try_from(value).ok()
+            }
+
+            fn try_from_i128(value: i128) -> Option<Self> {
+                <$t>::try_from(value).ok()
+            }
+
+            fn try_from_u128(value: u128) -> Option<Self> {
+                <$t>::try_from(value).ok()
+            }
+
+            // MAX is not always representable in f64, -MIN is the first value out of range
+            fn try_from_f64(value: f64) -> Option<Self> {
+                let value = value.trunc();
+                if value >= <$t>::MIN as f64 && value < -(<$t>::MIN as f64) { Some(value as $t) } else { None }
+            }
+
+            fn to<S> (&self) -> S where S: Numeric {
+                S::$entry(*self as $hub)
+            }
+
+            fn try_to<S> (&self) -> Result<S, NumericConversionError> where S: Numeric {
+                S::try_from_i128(*self as i128).ok_or_else(|| NumericConversionError::new(self))
+            }
+
+        }
+
+        impl Real for $t {}
+    };
 }
 
-impl Numeric for i64 {
-    fn one() -> Self {
-        1i64
-    }
+macro_rules! impl_unsigned_numeric {
+    ($t:ty, $entry:ident, $hub:ty) => {
+        impl Numeric for $t {
+            fn one() -> Self {
+                1
+            }
 
-    fn zero() -> Self {
-        0i64
-    }
+            fn zero() -> Self {
+                0
+            }
 
-    fn from_i32(value: i32) -> Self {
-        value as i64
-    }
+            fn from_i32(value: i32) -> Self {
+                value as $t
+            }
 
-    fn from_i64(value: i64) -> Self {
-        value
-    }
+            fn from_i64(value: i64) -> Self {
+                value as $t
+            }
 
-    fn from_usize(value: usize) -> Self {
-        value as i64
-    }
+            fn from_i128(value: i128) -> Self {
+                value as $t
+            }
 
-    fn from_f32(value: f32) -> Self {
-        value.trunc() as i64
-    }
+            fn from_u128(value: u128) -> Self {
+                value as $t
+            }
 
-    fn from_f64(value: f64) -> Self {
-        value.trunc() as i64
-    }
+            fn from_usize(value: usize) -> Self {
+                value as $t
+            }
 
-    fn to<S> (&self) -> S where S: Numeric {
-        S::from_i64(*self)
-    }
+            fn from_f32(value: f32) -> Self {
+                value.trunc() as $t
+            }
+
+            fn from_f64(value: f64) -> Self {
+                value.trunc() as $t
+            }
+
+            fn try_from_i64(value: i64) -> Option<Self> {
+                <$t>::try_from(value).ok()
+            }
+
+            fn try_from_i128(value: i128) -> Option<Self> {
+                <$t>::try_from(value).ok()
+            }
 
+            fn try_from_u128(value: u128) -> Option<Self> {
+                <$t>::try_from(value).ok()
+            }
+
+            // Truncation maps (-1, 0) to zero, 2^BITS is the first value out of range
+            fn try_from_f64(value: f64) -> Option<Self> {
+                let value = value.trunc();
+                if value >= 0f64 && value < 2f64.powi(<$t>::BITS as i32) { Some(value as $t) } else { None }
+            }
+
+            fn to<S> (&self) -> S where S: Numeric {
+                S::$entry(*self as $hub)
+            }
+
+            fn try_to<S> (&self) -> Result<S, NumericConversionError> where S: Numeric {
+                S::try_from_u128(*self as u128).ok_or_else(|| NumericConversionError::new(self))
+            }
+
+        }
+
+        impl Real for $t {}
+    };
 }
 
+impl_signed_numeric!(i8, from_i32, i32);
+impl_signed_numeric!(i16, from_i32, i32);
+impl_signed_numeric!(i32, from_i32, i32);
+impl_signed_numeric!(i64, from_i64, i64);
+impl_signed_numeric!(i128, from_i128, i128);
+impl_signed_numeric!(isize, from_i128, i128);
+impl_unsigned_numeric!(u8, from_i32, i32);
+impl_unsigned_numeric!(u16, from_i32, i32);
+impl_unsigned_numeric!(u32, from_i64, i64);
+impl_unsigned_numeric!(u64, from_u128, u128);
+impl_unsigned_numeric!(u128, from_u128, u128);
+impl_unsigned_numeric!(usize, from_usize, usize);
+
 impl Numeric for f32 {
     fn one() -> Self {
         1f32
@@ -107,6 +281,14 @@ impl Numeric for f32 {
         value as f32
     }
 
+    fn from_i128(value: i128) -> Self {
+        value as f32
+    }
+
+    fn from_u128(value: u128) -> Self {
+        value as f32
+    }
+
     fn from_usize(value: usize) -> Self {
         value as f32
     }
@@ -119,10 +301,24 @@ impl Numeric for f32 {
         value as f32
     }
 
+    fn significand_digits() -> u32 {
+        f32::MANTISSA_DIGITS
+    }
+
+    // Finite doubles beyond f32::MAX would silently become infinite
+    fn try_from_f64(value: f64) -> Option<Self> {
+        let converted = value as f32;
+        if converted.is_finite() || !value.is_finite() { Some(converted) } else { None }
+    }
+
     fn to<S> (&self) -> S where S: Numeric {
         S::from_f32(*self)
     }
 
+    fn try_to<S> (&self) -> Result<S, NumericConversionError> where S: Numeric {
+        S::try_from_f64(*self as f64).ok_or_else(|| NumericConversionError::new(self))
+    }
+
 }
 
 impl Numeric for f64 {
@@ -142,6 +338,14 @@ impl Numeric for f64 {
         value as f64
     }
 
+    fn from_i128(value: i128) -> Self {
+        value as f64
+    }
+
+    fn from_u128(value: u128) -> Self {
+        value as f64
+    }
+
     fn from_usize(value: usize) -> Self {
         value as f64
     }
@@ -158,4 +362,90 @@ impl Numeric for f64 {
         S::from_f64(*self)
     }
 
+    fn try_to<S> (&self) -> Result<S, NumericConversionError> where S: Numeric {
+        S::try_from_f64(*self).ok_or_else(|| NumericConversionError::new(self))
+    }
+
+}
+
+// Element types without an imaginary part, the only ones real-valued decompositions accept
+pub trait Real: Numeric {}
+
+impl Real for f32 {}
+impl Real for f64 {}
+
+pub trait Float:
+Real + ThreadSafe +
+ops::Neg<Output = Self> +
+ops::AddAssign + ops::SubAssign +
+ops::MulAssign + ops::DivAssign {
+    fn sqrt(self) -> Self;
+    fn abs(self) -> Self;
+    fn ln(self) -> Self;
+    fn hypot(self, other: Self) -> Self;
+    fn epsilon() -> Self;
+    fn is_finite(self) -> bool;
+    fn is_nan(self) -> bool;
+}
+
+impl Float for f32 {
+    fn sqrt(self) -> Self {
+        f32::sqrt(self)
+    }
+
+    fn abs(self) -> Self {
+        f32::abs(self)
+    }
+
+    fn ln(self) -> Self {
+        f32::ln(self)
+    }
+
+    fn hypot(self, other: Self) -> Self {
+        f32::hypot(self, other)
+    }
+
+    fn epsilon() -> Self {
+        f32::EPSILON
+    }
+
+    fn is_finite(self) -> bool {
+        f32::is_finite(self)
+    }
+
+    fn is_nan(self) -> bool {
+        f32::is_nan(self)
+    }
+
+}
+
+impl Float for f64 {
+    fn sqrt(self) -> Self {
+        f64::sqrt(self)
+    }
+
+    fn abs(self) -> Self {
+        f64::abs(self)
+    }
+
+    fn ln(self) -> Self {
+        f64::ln(self)
+    }
+
+    fn hypot(self, other: Self) -> Self {
+        f64::hypot(self, other)
+    }
+
+    fn epsilon() -> Self {
+        f64::EPSILON
+    }
+
+    fn is_finite(self) -> bool {
+        f64::is_finite(self)
+    }
+
+    fn is_nan(self) -> bool {
+        f64::is_nan(self)
+    }
+
 }
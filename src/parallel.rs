@@ -0,0 +1,64 @@
+use std::sync::atomic::{ AtomicUsize, Ordering };
+use std::thread;
+
+// Below this many multiply-adds the serial path is used, spawning threads would cost more than it saves
+pub(crate) const MIN_PARALLEL_WORK: usize = 1 << 16;
+
+static THREADS: AtomicUsize = AtomicUsize::new(0);
+
+// Number of worker threads, 0 restores the default of one per available core
+pub fn set_threads(threads: usize) {
+    THREADS.store(threads, Ordering::Relaxed);
+}
+
+pub fn threads() -> usize {
+    match THREADS.load(Ordering::Relaxed) {
+        0 => thread::available_parallelism().map(|n| n.get()).unwrap_or(1),
+        threads => threads,
+    }
+}
+
+// Calls f(row index, row) on every row of a row-major buffer, each of up to `threads` threads getting a contiguous block of rows
+pub(crate) fn for_each_row<T, F>(data: &mut [T], row_len: usize, threads: usize, f: F)
+where T: Send, F: Fn(usize, &mut [T]) + Sync {
+    if row_len == 0 { return }
+    let rows = data.len() / row_len;
+    let block = rows.div_ceil(threads.clamp(1, rows.max(1)));
+    if block >= rows {
+        for (r, row) in data.chunks_mut(row_len).enumerate() { f(r, row); }
+        return
+    }
+    thread::scope(|scope| {
+        for (b, chunk) in data.chunks_mut(block * row_len).enumerate() {
+            let f = &f;
+            scope.spawn(move || {
+                for (r, row) in chunk.chunks_mut(row_len).enumerate() {
+                    f(b * block + r, row);
+                }
+            });
+        }
+    });
+}
+
+// Runs f on every job on up to `threads` threads, results come back in the order of the jobs
+pub(crate) fn map<J, R, F>(jobs: Vec<J>, threads: usize, f: F) -> Vec<R>
+where J: Send, R: Send, F: Fn(J) -> R + Sync {
+    let block = jobs.len().div_ceil(threads.clamp(1, jobs.len().max(1))).max(1);
+    if block >= jobs.len() { return jobs.into_iter().map(f).collect() }
+    let mut jobs = jobs.into_iter();
+    let mut batches = Vec::new();
+    loop {
+        let batch: Vec<J> = jobs.by_ref().take(block).collect();
+        if batch.is_empty() { break }
+        batches.push(batch);
+    }
+    thread::scope(|scope| {
+        let handles: Vec<_> = batches.into_iter()
+            .map(|batch| {
+                let f = &f;
+                scope.spawn(move || batch.into_iter().map(f).collect::<Vec<R>>())
+            })
+            .collect();
+        handles.into_iter().flat_map(|handle| handle.join().unwrap()).collect()
+    })
+}
@@ -0,0 +1,180 @@
+use std::fmt;
+
+use crate::numeric::Real;
+use crate::matrix::{ Matrix, MatrixBuilder, Swap };
+use crate::triangular::solve_upper_in_place;
+
+#[derive(Debug, PartialEq)]
+pub enum QrDecompositionError {
+    MatrixIsRankDeficient,
+    DimensionMismatch,
+}
+
+impl fmt::Display for QrDecompositionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            QrDecompositionError::MatrixIsRankDeficient => {
+                write!(f, "QR solve failed because matrix is rank deficient, use column pivoting.")
+            },
+            QrDecompositionError::DimensionMismatch => {
+                write!(f, "Right hand side dimensions are not consistent with the decomposed matrix.")
+            },
+        }
+    }
+}
+
+impl std::error::Error for QrDecompositionError {}
+
+#[derive(Debug, Clone)]
+pub struct QrDecomposition {
+    r: Matrix<f64>,
+    reflectors: Vec<(Vec<f64>, f64)>,
+    permutation: Vec<usize>,
+    rank: usize,
+    pivoted: bool,
+}
+
+pub trait Qr {
+    fn qr(&self) -> QrDecomposition;
+    fn qr_pivoted(&self) -> QrDecomposition;
+}
+
+impl<T> Qr for Matrix<T> where T: Real {
+    fn qr(&self) -> QrDecomposition {
+        QrDecomposition::decompose(self, false)
+    }
+
+    fn qr_pivoted(&self) -> QrDecomposition {
+        QrDecomposition::decompose(self, true)
+    }
+}
+
+impl QrDecomposition {
+
+    // Householder reflections H_k = I - beta_k·v_k·v_kᵀ with A·P = H_0·…·H_{k-1}·R
+    pub fn decompose<T>(m: &Matrix<T>, pivoting: bool) -> Self where T: Real {
+        let (rows, cols) = (m.rows(), m.cols());
+        let steps = rows.min(cols);
+        let mut r = m.copy_to::<f64>().unwrap();
+        let mut permutation: Vec<usize> = (0..cols).collect();
+        let mut reflectors = Vec::with_capacity(steps);
+        for j in 0..steps {
+            if pivoting {
+                let norm = |c: usize| (j..rows).map(|i| r[(i, c)] * r[(i, c)]).sum::<f64>();
+                let mut p = j;
+                let mut norm_max = norm(j);
+                for c in (j + 1)..cols {
+                    let n = norm(c);
+                    if n > norm_max {
+                        p = c;
+                        norm_max = n;
+                    }
+                }
+                if p != j {
+                    r.swap_cols(j, p).unwrap();
+                    permutation.swap(j, p);
+                }
+            }
+            let mut v: Vec<f64> = (j..rows).map(|i| r[(i, j)]).collect();
+            let norm = v.iter().map(|x| x * x).sum::<f64>().sqrt();
+            if norm == 0f64 {
+                reflectors.push((v, 0f64));
+                continue
+            }
+            let alpha = if v[0] > 0f64 { -norm } else { norm };
+            v[0] -= alpha;
+            let beta = 2f64 / v.iter().map(|x| x * x).sum::<f64>();
+            apply_reflector(&mut r, &v, beta, j, j + 1);
+            r[(j, j)] = alpha;
+            for i in (j + 1)..rows { r[(i, j)] = 0f64; }
+            reflectors.push((v, beta));
+        }
+        let r_max = if steps > 0 { r[(0, 0)].abs() } else { 0f64 };
+        let tolerance = r_max * f64::EPSILON * rows.max(cols) as f64;
+        let rank = (0..steps).filter(|&k| r[(k, k)].abs() > tolerance).count();
+        QrDecomposition { r, reflectors, permutation, rank, pivoted: pivoting }
+    }
+
+    pub fn permutation(&self) -> &[usize] {
+        &self.permutation
+    }
+
+    pub fn rank(&self) -> usize {
+        self.rank
+    }
+
+    pub fn r(&self) -> Matrix<f64> {
+        self.r.clone()
+    }
+
+    pub fn thin_r(&self) -> Matrix<f64> {
+        let steps = self.reflectors.len();
+        self.r.submatrix(..steps, ..).unwrap()
+    }
+
+    pub fn q(&self) -> Matrix<f64> {
+        let rows = self.r.rows();
+        self.q_with_cols(rows)
+    }
+
+    pub fn thin_q(&self) -> Matrix<f64> {
+        self.q_with_cols(self.reflectors.len())
+    }
+
+    fn q_with_cols(&self, cols: usize) -> Matrix<f64> {
+        let rows = self.r.rows();
+        let mut q = MatrixBuilder::<f64>::new().zeros(rows, cols).build().unwrap();
+        for k in 0..cols { q[(k, k)] = 1f64; }
+        for (j, (v, beta)) in self.reflectors.iter().enumerate().rev() {
+            apply_reflector(&mut q, v, *beta, j, 0);
+        }
+        q
+    }
+
+    pub fn apply_qt(&self, b: &mut Matrix<f64>) -> Result<(), QrDecompositionError> {
+        if b.rows() != self.r.rows() { return Err(QrDecompositionError::DimensionMismatch) }
+        for (j, (v, beta)) in self.reflectors.iter().enumerate() {
+            apply_reflector(b, v, *beta, j, 0);
+        }
+        Ok(())
+    }
+
+    // Basic least squares solution: variables past the numerical rank are set to zero
+    pub fn solve_least_squares<T>(&self, b: &Matrix<T>) -> Result<Matrix<f64>, QrDecompositionError> where T: Real {
+        let mut qtb = b.copy_to::<f64>().unwrap();
+        self.apply_qt(&mut qtb)?;
+        let rank = self.rank;
+        if !self.pivoted && rank < self.reflectors.len().min(self.r.cols()) {
+            return Err(QrDecompositionError::MatrixIsRankDeficient)
+        }
+        let cols = self.r.cols();
+        let mut x = MatrixBuilder::<f64>::new().zeros(cols, b.cols()).build().unwrap();
+        if rank == 0 { return Ok(x) }
+        let r11 = self.r.submatrix(..rank, ..rank).unwrap();
+        let mut z = qtb.submatrix(..rank, ..).unwrap();
+        solve_upper_in_place(&r11, &mut z);
+        for i in 0..rank {
+            for j in 0..b.cols() {
+                x[(self.permutation[i], j)] = z[(i, j)];
+            }
+        }
+        Ok(x)
+    }
+
+}
+
+pub fn least_squares<T, S>(a: &Matrix<T>, b: &Matrix<S>) -> Result<Matrix<f64>, QrDecompositionError> 
+where T: Real, S: Real {
+    a.qr_pivoted().solve_least_squares(b)
+}
+
+// Applies I - beta·v·vᵀ to rows j.. and columns from_col.. of a
+fn apply_reflector(a: &mut Matrix<f64>, v: &[f64], beta: f64, j: usize, from_col: usize) {
+    if beta == 0f64 { return }
+    for c in from_col..a.cols() {
+        let s = beta * v.iter().enumerate().map(|(i, vi)| vi * a[(j + i, c)]).sum::<f64>();
+        for (i, vi) in v.iter().enumerate() {
+            a[(j + i, c)] -= s * vi;
+        }
+    }
+}
@@ -0,0 +1,320 @@
+use std::fmt;
+use std::ops;
+use std::cmp;
+
+use crate::numeric::{ Numeric, Real, NumericConversionError };
+
+#[derive(Debug, PartialEq)]
+pub enum RationalError {
+    ZeroDenominator,
+    Overflow,
+}
+
+impl fmt::Display for RationalError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            RationalError::ZeroDenominator => {
+                write!(f, "Rational number cannot have a zero denominator.")
+            },
+            RationalError::Overflow => {
+                write!(f, "Rational arithmetic overflowed 128 bit numerator or denominator.")
+            },
+        }
+    }
+}
+
+impl std::error::Error for RationalError {}
+
+// Always normalized: denominator positive and coprime with numerator, so derived equality is exact
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numerator: i128,
+    denominator: i128,
+}
+
+impl Rational {
+
+    pub fn new(numerator: i128, denominator: i128) -> Result<Self, RationalError> {
+        if denominator == 0 { return Err(RationalError::ZeroDenominator) }
+        if numerator == i128::MIN || denominator == i128::MIN { return Err(RationalError::Overflow) }
+        let g = gcd(numerator, denominator);
+        let (numerator, denominator) = (numerator / g, denominator / g);
+        if denominator < 0 {
+            Ok(Rational { numerator: -numerator, denominator: -denominator })
+        } else {
+            Ok(Rational { numerator, denominator })
+        }
+    }
+
+    pub fn integer(value: i128) -> Result<Self, RationalError> {
+        Rational::new(value, 1)
+    }
+
+    pub fn numerator(&self) -> i128 {
+        self.numerator
+    }
+
+    pub fn denominator(&self) -> i128 {
+        self.denominator
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.numerator == 0
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.denominator == 1
+    }
+
+    pub fn recip(&self) -> Result<Self, RationalError> {
+        Rational::new(self.denominator, self.numerator)
+    }
+
+    pub fn checked_add(&self, other: &Self) -> Result<Self, RationalError> {
+        let g = gcd(self.denominator, other.denominator);
+        let (b, d) = (self.denominator / g, other.denominator / g);
+        let left = self.numerator.checked_mul(d).ok_or(RationalError::Overflow)?;
+        let right = other.numerator.checked_mul(b).ok_or(RationalError::Overflow)?;
+        let numerator = left.checked_add(right).ok_or(RationalError::Overflow)?;
+        let denominator = b.checked_mul(other.denominator).ok_or(RationalError::Overflow)?;
+        Rational::new(numerator, denominator)
+    }
+
+    pub fn checked_sub(&self, other: &Self) -> Result<Self, RationalError> {
+        self.checked_add(&other.checked_neg()?)
+    }
+
+    // Cross cancels before multiplying to keep intermediate values small
+    pub fn checked_mul(&self, other: &Self) -> Result<Self, RationalError> {
+        let g1 = gcd(self.numerator, other.denominator);
+        let g2 = gcd(other.numerator, self.denominator);
+        let numerator = (self.numerator / g1).checked_mul(other.numerator / g2).ok_or(RationalError::Overflow)?;
+        let denominator = (self.denominator / g2).checked_mul(other.denominator / g1).ok_or(RationalError::Overflow)?;
+        Rational::new(numerator, denominator)
+    }
+
+    pub fn checked_div(&self, other: &Self) -> Result<Self, RationalError> {
+        self.checked_mul(&other.recip()?)
+    }
+
+    pub fn checked_neg(&self) -> Result<Self, RationalError> {
+        Rational::new(-self.numerator, self.denominator)
+    }
+
+    pub fn to_f64(&self) -> f64 {
+        self.numerator as f64 / self.denominator as f64
+    }
+
+    // Integers go through i128 so they stay exact, anything else through f64
+    pub(crate) fn convert<S>(self) -> S where S: Numeric {
+        if self.denominator == 1 {
+            S::from_i128(self.numerator)
+        } else {
+            S::from_f64(self.to_f64())
+        }
+    }
+
+    pub(crate) fn try_convert<S>(self) -> Option<S> where S: Numeric {
+        if self.denominator == 1 {
+            S::try_from_i128(self.numerator)
+        } else {
+            S::try_from_f64(self.to_f64())
+        }
+    }
+
+}
+
+impl Default for Rational {
+    fn default() -> Self {
+        Rational { numerator: 0, denominator: 1 }
+    }
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.denominator == 1 {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+// Compares continued fraction expansions, exact without any multiplication that could overflow
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        let (mut a, mut b) = (self.numerator, self.denominator);
+        let (mut c, mut d) = (other.numerator, other.denominator);
+        let mut reversed = false;
+        loop {
+            let (q1, r1) = (a.div_euclid(b), a.rem_euclid(b));
+            let (q2, r2) = (c.div_euclid(d), c.rem_euclid(d));
+            let ordering = match (q1.cmp(&q2), r1, r2) {
+                (cmp::Ordering::Equal, 0, 0) => cmp::Ordering::Equal,
+                (cmp::Ordering::Equal, 0, _) => cmp::Ordering::Less,
+                (cmp::Ordering::Equal, _, 0) => cmp::Ordering::Greater,
+                (cmp::Ordering::Equal, _, _) => {
+                    (a, b, c, d) = (b, r1, d, r2);
+                    reversed = !reversed;
+                    continue
+                },
+                (ordering, _, _) => ordering,
+            };
+            return if reversed { ordering.reverse() } else { ordering }
+        }
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// Operators panic on overflow like primitive integers do in debug builds, checked_* methods report it instead
+impl ops::Add for Rational {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        self.checked_add(&other).expect("rational addition overflowed")
+    }
+}
+
+impl ops::Sub for Rational {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self.checked_sub(&other).expect("rational subtraction overflowed")
+    }
+}
+
+impl ops::Mul for Rational {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        self.checked_mul(&other).expect("rational multiplication overflowed")
+    }
+}
+
+impl ops::Div for Rational {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        self.checked_div(&other).expect("rational division by zero or overflow")
+    }
+}
+
+impl ops::Neg for Rational {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self.checked_neg().expect("rational negation overflowed")
+    }
+}
+
+// Integers and floats are converted exactly when they fit, saturating like `as` casts otherwise
+impl Numeric for Rational {
+    fn one() -> Self {
+        Rational { numerator: 1, denominator: 1 }
+    }
+
+    fn zero() -> Self {
+        Rational { numerator: 0, denominator: 1 }
+    }
+
+    fn from_i32(value: i32) -> Self {
+        Rational { numerator: value as i128, denominator: 1 }
+    }
+
+    fn from_i64(value: i64) -> Self {
+        Rational { numerator: value as i128, denominator: 1 }
+    }
+
+    fn from_usize(value: usize) -> Self {
+        Rational { numerator: value as i128, denominator: 1 }
+    }
+
+    fn from_i128(value: i128) -> Self {
+        Rational { numerator: value.max(-i128::MAX), denominator: 1 }
+    }
+
+    fn from_u128(value: u128) -> Self {
+        Rational { numerator: i128::try_from(value).unwrap_or(i128::MAX), denominator: 1 }
+    }
+
+    fn from_f32(value: f32) -> Self {
+        Rational::from_f64(value as f64)
+    }
+
+    fn from_f64(value: f64) -> Self {
+        if value.is_nan() { return Rational::zero() }
+        if value.is_infinite() {
+            let numerator = if value > 0f64 { i128::MAX } else { -i128::MAX };
+            return Rational { numerator, denominator: 1 }
+        }
+        let bits = value.to_bits();
+        let biased = ((bits >> 52) & 0x7ff) as i32;
+        let fraction = (bits & ((1u64 << 52) - 1)) as i128;
+        let (mut mantissa, mut exponent) = if biased == 0 { (fraction, -1074) } else { (fraction | 1i128 << 52, biased - 1075) };
+        if mantissa == 0 { return Rational::zero() }
+        let zeros = mantissa.trailing_zeros() as i32;
+        mantissa >>= zeros;
+        exponent += zeros;
+        if value < 0f64 { mantissa = -mantissa }
+        if exponent >= 0 {
+            if 128 - mantissa.unsigned_abs().leading_zeros() as i32 + exponent > 127 {
+                let numerator = if value > 0f64 { i128::MAX } else { -i128::MAX };
+                return Rational { numerator, denominator: 1 }
+            }
+            Rational { numerator: mantissa << exponent, denominator: 1 }
+        } else if exponent >= -126 {
+            Rational { numerator: mantissa, denominator: 1i128 << -exponent }
+        } else {
+            // Too small for a 128 bit denominator, keep the closest multiple of 2^-126
+            Rational::new(mantissa >> (-126 - exponent).min(127), 1i128 << 126).unwrap()
+        }
+    }
+
+    fn from_rational(value: Rational) -> Self {
+        value
+    }
+
+    // i128::MIN has no positive counterpart, it is kept out so negation cannot overflow
+    fn try_from_i128(value: i128) -> Option<Self> {
+        Rational::integer(value).ok()
+    }
+
+    fn try_from_u128(value: u128) -> Option<Self> {
+        Rational::integer(i128::try_from(value).ok()?).ok()
+    }
+
+    // Beyond 2^127 from_f64 saturates and below 2^-126 it rounds, only exact conversions succeed
+    fn try_from_f64(value: f64) -> Option<Self> {
+        if !value.is_finite() || value.abs() >= 2f64.powi(127) { return None }
+        let converted = Rational::from_f64(value);
+        if converted.to_f64() == value { Some(converted) } else { None }
+    }
+
+    fn try_from_rational(value: Rational) -> Option<Self> {
+        Some(value)
+    }
+
+    fn to<S> (&self) -> S where S: Numeric {
+        S::from_rational(*self)
+    }
+
+    fn try_to<S> (&self) -> Result<S, NumericConversionError> where S: Numeric {
+        S::try_from_rational(*self).ok_or_else(|| NumericConversionError::new(self))
+    }
+
+}
+
+impl Real for Rational {}
+
+fn gcd(a: i128, b: i128) -> i128 {
+    let (mut a, mut b) = (a.unsigned_abs(), b.unsigned_abs());
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    if a == 0 { 1 } else { a as i128 }
+}
@@ -0,0 +1,481 @@
+use crate::numeric::Real;
+use crate::matrix::{ Matrix, MatrixBuilder };
+use crate::square::Square;
+use crate::complex::Complex;
+use crate::symmetric::EigenDecompositionError;
+
+#[derive(Debug, Clone)]
+pub struct RealSchurDecomposition {
+    q: Matrix<f64>,
+    t: Matrix<f64>,
+    eigen_values: Vec<Complex<f64>>,
+}
+
+impl RealSchurDecomposition {
+
+    pub fn q(&self) -> &Matrix<f64> {
+        &self.q
+    }
+
+    pub fn t(&self) -> &Matrix<f64> {
+        &self.t
+    }
+
+    pub fn eigen_values(&self) -> &[Complex<f64>] {
+        &self.eigen_values
+    }
+
+}
+
+#[derive(Debug, Clone)]
+pub struct GeneralEigenDecomposition {
+    eigen_values: Vec<Complex<f64>>,
+    eigen_vectors: Matrix<f64>,
+}
+
+impl GeneralEigenDecomposition {
+
+    pub fn eigen_values(&self) -> &[Complex<f64>] {
+        &self.eigen_values
+    }
+
+    // Real eigen values own a real column, a complex conjugate pair (j, j + 1)
+    // stores the real part of the eigen vector of eigen_values[j] in column j
+    // and its imaginary part in column j + 1
+    pub fn packed_eigen_vectors(&self) -> &Matrix<f64> {
+        &self.eigen_vectors
+    }
+
+    pub fn eigen_vector(&self, j: usize) -> Vec<Complex<f64>> {
+        let n = self.eigen_vectors.rows();
+        let v = &self.eigen_vectors;
+        let lambda = self.eigen_values[j];
+        if lambda.is_real() {
+            (0..n).map(|i| Complex::new(v[(i, j)], 0f64)).collect()
+        } else if lambda.im > 0f64 {
+            (0..n).map(|i| Complex::new(v[(i, j)], v[(i, j + 1)])).collect()
+        } else {
+            (0..n).map(|i| Complex::new(v[(i, j - 1)], -v[(i, j)])).collect()
+        }
+    }
+
+}
+
+pub trait GeneralEigen {
+    fn schur(&self) -> Result<RealSchurDecomposition, EigenDecompositionError>;
+    fn general_eigen_values(&self) -> Result<Vec<Complex<f64>>, EigenDecompositionError>;
+    fn general_eigen_decomposition(&self) -> Result<GeneralEigenDecomposition, EigenDecompositionError>;
+}
+
+impl<T> GeneralEigen for Matrix<T> where T: Real {
+    fn schur(&self) -> Result<RealSchurDecomposition, EigenDecompositionError> {
+        if !self.is_square() { return Err(EigenDecompositionError) }
+        let mut h = self.copy_to::<f64>().unwrap();
+        let mut v = orthes(&mut h);
+        let (d, e, _) = hqr2(&mut h, &mut v)?;
+        let eigen_values: Vec<Complex<f64>> = d.iter().zip(e.iter()).map(|(&re, &im)| Complex::new(re, im)).collect();
+        let n = h.rows();
+        for i in 1..n {
+            for j in 0..(i - 1) { h[(i, j)] = 0f64; }
+            let is_pair = eigen_values[i - 1].im > 0f64;
+            if !is_pair { h[(i, i - 1)] = 0f64; }
+        }
+        Ok(RealSchurDecomposition { q: v, t: h, eigen_values })
+    }
+
+    fn general_eigen_values(&self) -> Result<Vec<Complex<f64>>, EigenDecompositionError> {
+        Ok(self.schur()?.eigen_values)
+    }
+
+    fn general_eigen_decomposition(&self) -> Result<GeneralEigenDecomposition, EigenDecompositionError> {
+        if !self.is_square() { return Err(EigenDecompositionError) }
+        let mut h = self.copy_to::<f64>().unwrap();
+        let mut v = orthes(&mut h);
+        let (d, e, norm) = hqr2(&mut h, &mut v)?;
+        back_substitute(&mut h, &mut v, &d, &e, norm);
+        let eigen_values = d.iter().zip(e.iter()).map(|(&re, &im)| Complex::new(re, im)).collect();
+        Ok(GeneralEigenDecomposition { eigen_values, eigen_vectors: v })
+    }
+}
+
+// Source: JAMA EigenvalueDecomposition (public domain), derived from EISPACK orthes, hqr2
+// Orthogonal reduction to Hessenberg form, returns the accumulated transformation
+fn orthes(h: &mut Matrix<f64>) -> Matrix<f64> {
+    let n = h.rows();
+    let high = n - 1;
+    let mut ort = vec![0f64; n];
+    for m in 1..high {
+        let mut scale = 0f64;
+        for i in m..=high { scale += h[(i, m - 1)].abs(); }
+        if scale != 0f64 {
+            let mut hh = 0f64;
+            for i in (m..=high).rev() {
+                ort[i] = h[(i, m - 1)] / scale;
+                hh += ort[i] * ort[i];
+            }
+            let mut g = hh.sqrt();
+            if ort[m] > 0f64 { g = -g; }
+            hh -= ort[m] * g;
+            ort[m] -= g;
+            for j in m..n {
+                let mut f = 0f64;
+                for i in (m..=high).rev() { f += ort[i] * h[(i, j)]; }
+                f /= hh;
+                for i in m..=high { h[(i, j)] -= f * ort[i]; }
+            }
+            for i in 0..=high {
+                let mut f = 0f64;
+                for j in (m..=high).rev() { f += ort[j] * h[(i, j)]; }
+                f /= hh;
+                for j in m..=high { h[(i, j)] -= f * ort[j]; }
+            }
+            ort[m] *= scale;
+            h[(m, m - 1)] = scale * g;
+        }
+    }
+    let mut v = MatrixBuilder::<f64>::new().identity(n).build().unwrap();
+    for m in (1..high).rev() {
+        if h[(m, m - 1)] != 0f64 {
+            for i in (m + 1)..=high { ort[i] = h[(i, m - 1)]; }
+            for j in m..=high {
+                let mut g = 0f64;
+                for i in m..=high { g += ort[i] * v[(i, j)]; }
+                g = (g / ort[m]) / h[(m, m - 1)];
+                for i in m..=high { v[(i, j)] += g * ort[i]; }
+            }
+        }
+    }
+    for i in 2..n {
+        for j in 0..(i - 1) { h[(i, j)] = 0f64; }
+    }
+    v
+}
+
+// Francis double shift QR iterations down to real Schur form, returns (re, im, norm)
+fn hqr2(h: &mut Matrix<f64>, v: &mut Matrix<f64>) -> Result<(Vec<f64>, Vec<f64>, f64), EigenDecompositionError> {
+    let nn = h.rows();
+    let low = 0usize;
+    let high = nn - 1;
+    let eps = f64::EPSILON;
+    let mut d = vec![0f64; nn];
+    let mut e = vec![0f64; nn];
+    let mut exshift = 0f64;
+    let (mut p, mut q, mut r, mut s, mut z): (f64, f64, f64, f64, f64);
+    let (mut w, mut x, mut y): (f64, f64, f64);
+
+    let mut norm = 0f64;
+    for i in 0..nn {
+        for j in i.saturating_sub(1)..nn { norm += h[(i, j)].abs(); }
+    }
+
+    let mut n = nn as isize - 1;
+    let mut iter = 0;
+    while n >= low as isize {
+        let nu = n as usize;
+        let mut l = nu;
+        while l > low {
+            s = h[(l - 1, l - 1)].abs() + h[(l, l)].abs();
+            if s == 0f64 { s = norm; }
+            if h[(l, l - 1)].abs() < eps * s { break }
+            l -= 1;
+        }
+
+        if l == nu {
+            h[(nu, nu)] += exshift;
+            d[nu] = h[(nu, nu)];
+            e[nu] = 0f64;
+            n -= 1;
+            iter = 0;
+        } else if l + 1 == nu {
+            w = h[(nu, nu - 1)] * h[(nu - 1, nu)];
+            p = (h[(nu - 1, nu - 1)] - h[(nu, nu)]) / 2f64;
+            q = p * p + w;
+            z = q.abs().sqrt();
+            h[(nu, nu)] += exshift;
+            h[(nu - 1, nu - 1)] += exshift;
+            x = h[(nu, nu)];
+            if q >= 0f64 {
+                z = if p >= 0f64 { p + z } else { p - z };
+                d[nu - 1] = x + z;
+                d[nu] = d[nu - 1];
+                if z != 0f64 { d[nu] = x - w / z; }
+                e[nu - 1] = 0f64;
+                e[nu] = 0f64;
+                x = h[(nu, nu - 1)];
+                s = x.abs() + z.abs();
+                p = x / s;
+                q = z / s;
+                r = (p * p + q * q).sqrt();
+                p /= r;
+                q /= r;
+                for j in (nu - 1)..nn {
+                    z = h[(nu - 1, j)];
+                    h[(nu - 1, j)] = q * z + p * h[(nu, j)];
+                    h[(nu, j)] = q * h[(nu, j)] - p * z;
+                }
+                for i in 0..=nu {
+                    z = h[(i, nu - 1)];
+                    h[(i, nu - 1)] = q * z + p * h[(i, nu)];
+                    h[(i, nu)] = q * h[(i, nu)] - p * z;
+                }
+                for i in low..=high {
+                    z = v[(i, nu - 1)];
+                    v[(i, nu - 1)] = q * z + p * v[(i, nu)];
+                    v[(i, nu)] = q * v[(i, nu)] - p * z;
+                }
+            } else {
+                d[nu - 1] = x + p;
+                d[nu] = x + p;
+                e[nu - 1] = z;
+                e[nu] = -z;
+            }
+            n -= 2;
+            iter = 0;
+        } else {
+            x = h[(nu, nu)];
+            y = 0f64;
+            w = 0f64;
+            if l < nu {
+                y = h[(nu - 1, nu - 1)];
+                w = h[(nu, nu - 1)] * h[(nu - 1, nu)];
+            }
+
+            // Wilkinson's original ad hoc shift
+            if iter == 10 {
+                exshift += x;
+                for i in low..=nu { h[(i, i)] -= x; }
+                s = h[(nu, nu - 1)].abs() + h[(nu - 1, nu - 2)].abs();
+                x = 0.75f64 * s;
+                y = x;
+                w = -0.4375f64 * s * s;
+            }
+
+            // MATLAB's ad hoc shift
+            if iter == 30 {
+                s = (y - x) / 2f64;
+                s = s * s + w;
+                if s > 0f64 {
+                    s = s.sqrt();
+                    if y < x { s = -s; }
+                    s = x - w / ((y - x) / 2f64 + s);
+                    for i in low..=nu { h[(i, i)] -= s; }
+                    exshift += s;
+                    x = 0.964f64;
+                    y = x;
+                    w = x;
+                }
+            }
+
+            iter += 1;
+            if iter > 30 * nn.max(2) { return Err(EigenDecompositionError) }
+
+            // Look for two consecutive small sub-diagonal elements
+            let mut m = nu - 2;
+            loop {
+                z = h[(m, m)];
+                r = x - z;
+                s = y - z;
+                p = (r * s - w) / h[(m + 1, m)] + h[(m, m + 1)];
+                q = h[(m + 1, m + 1)] - z - r - s;
+                r = h[(m + 2, m + 1)];
+                s = p.abs() + q.abs() + r.abs();
+                p /= s;
+                q /= s;
+                r /= s;
+                if m == l { break }
+                if h[(m, m - 1)].abs() * (q.abs() + r.abs()) <
+                    eps * (p.abs() * (h[(m - 1, m - 1)].abs() + z.abs() + h[(m + 1, m + 1)].abs())) {
+                    break
+                }
+                m -= 1;
+            }
+
+            for i in (m + 2)..=nu {
+                h[(i, i - 2)] = 0f64;
+                if i > m + 2 { h[(i, i - 3)] = 0f64; }
+            }
+
+            // Double QR step involving rows l..=n and columns m..=n
+            for k in m..nu {
+                let notlast = k != nu - 1;
+                x = 0f64;
+                if k != m {
+                    p = h[(k, k - 1)];
+                    q = h[(k + 1, k - 1)];
+                    r = if notlast { h[(k + 2, k - 1)] } else { 0f64 };
+                    x = p.abs() + q.abs() + r.abs();
+                    if x == 0f64 { continue }
+                    p /= x;
+                    q /= x;
+                    r /= x;
+                }
+                s = (p * p + q * q + r * r).sqrt();
+                if p < 0f64 { s = -s; }
+                if s != 0f64 {
+                    if k != m {
+                        h[(k, k - 1)] = -s * x;
+                    } else if l != m {
+                        h[(k, k - 1)] = -h[(k, k - 1)];
+                    }
+                    p += s;
+                    x = p / s;
+                    y = q / s;
+                    z = r / s;
+                    q /= p;
+                    r /= p;
+
+                    for j in k..nn {
+                        p = h[(k, j)] + q * h[(k + 1, j)];
+                        if notlast {
+                            p += r * h[(k + 2, j)];
+                            h[(k + 2, j)] -= p * z;
+                        }
+                        h[(k, j)] -= p * x;
+                        h[(k + 1, j)] -= p * y;
+                    }
+                    for i in 0..=nu.min(k + 3) {
+                        p = x * h[(i, k)] + y * h[(i, k + 1)];
+                        if notlast {
+                            p += z * h[(i, k + 2)];
+                            h[(i, k + 2)] -= p * r;
+                        }
+                        h[(i, k)] -= p;
+                        h[(i, k + 1)] -= p * q;
+                    }
+                    for i in low..=high {
+                        p = x * v[(i, k)] + y * v[(i, k + 1)];
+                        if notlast {
+                            p += z * v[(i, k + 2)];
+                            v[(i, k + 2)] -= p * r;
+                        }
+                        v[(i, k)] -= p;
+                        v[(i, k + 1)] -= p * q;
+                    }
+                }
+            }
+        }
+    }
+    Ok((d, e, norm))
+}
+
+fn cdiv(xr: f64, xi: f64, yr: f64, yi: f64) -> (f64, f64) {
+    if yr.abs() > yi.abs() {
+        let r = yi / yr;
+        let d = yr + r * yi;
+        ((xr + r * xi) / d, (xi - r * xr) / d)
+    } else {
+        let r = yr / yi;
+        let d = yi + r * yr;
+        ((r * xr + xi) / d, (r * xi - xr) / d)
+    }
+}
+
+// Eigen vectors of the quasi triangular form, then back transformation through v
+fn back_substitute(h: &mut Matrix<f64>, v: &mut Matrix<f64>, d: &[f64], e: &[f64], norm: f64) {
+    let nn = h.rows();
+    let eps = f64::EPSILON;
+    if norm == 0f64 { return }
+    let (mut r, mut s, mut z) = (0f64, 0f64, 0f64);
+    let (mut t, mut w, mut x, mut y): (f64, f64, f64, f64);
+
+    for n in (0..nn).rev() {
+        let p = d[n];
+        let q = e[n];
+        if q == 0f64 {
+            let mut l = n;
+            h[(n, n)] = 1f64;
+            for i in (0..n).rev() {
+                w = h[(i, i)] - p;
+                r = 0f64;
+                for j in l..=n { r += h[(i, j)] * h[(j, n)]; }
+                if e[i] < 0f64 {
+                    z = w;
+                    s = r;
+                } else {
+                    l = i;
+                    if e[i] == 0f64 {
+                        h[(i, n)] = if w != 0f64 { -r / w } else { -r / (eps * norm) };
+                    } else {
+                        x = h[(i, i + 1)];
+                        y = h[(i + 1, i)];
+                        let qq = (d[i] - p) * (d[i] - p) + e[i] * e[i];
+                        t = (x * s - z * r) / qq;
+                        h[(i, n)] = t;
+                        h[(i + 1, n)] = if x.abs() > z.abs() { (-r - w * t) / x } else { (-s - y * t) / z };
+                    }
+                    t = h[(i, n)].abs();
+                    if (eps * t) * t > 1f64 {
+                        for j in i..=n { h[(j, n)] /= t; }
+                    }
+                }
+            }
+        } else if q < 0f64 {
+            let mut l = n - 1;
+            if h[(n, n - 1)].abs() > h[(n - 1, n)].abs() {
+                h[(n - 1, n - 1)] = q / h[(n, n - 1)];
+                h[(n - 1, n)] = -(h[(n, n)] - p) / h[(n, n - 1)];
+            } else {
+                let (cr, ci) = cdiv(0f64, -h[(n - 1, n)], h[(n - 1, n - 1)] - p, q);
+                h[(n - 1, n - 1)] = cr;
+                h[(n - 1, n)] = ci;
+            }
+            h[(n, n - 1)] = 0f64;
+            h[(n, n)] = 1f64;
+            for i in (0..n.saturating_sub(1)).rev() {
+                let mut ra = 0f64;
+                let mut sa = 0f64;
+                for j in l..=n {
+                    ra += h[(i, j)] * h[(j, n - 1)];
+                    sa += h[(i, j)] * h[(j, n)];
+                }
+                w = h[(i, i)] - p;
+                if e[i] < 0f64 {
+                    z = w;
+                    r = ra;
+                    s = sa;
+                } else {
+                    l = i;
+                    if e[i] == 0f64 {
+                        let (cr, ci) = cdiv(-ra, -sa, w, q);
+                        h[(i, n - 1)] = cr;
+                        h[(i, n)] = ci;
+                    } else {
+                        x = h[(i, i + 1)];
+                        y = h[(i + 1, i)];
+                        let mut vr = (d[i] - p) * (d[i] - p) + e[i] * e[i] - q * q;
+                        let vi = (d[i] - p) * 2f64 * q;
+                        if vr == 0f64 && vi == 0f64 {
+                            vr = eps * norm * (w.abs() + q.abs() + x.abs() + y.abs() + z.abs());
+                        }
+                        let (cr, ci) = cdiv(x * r - z * ra + q * sa, x * s - z * sa - q * ra, vr, vi);
+                        h[(i, n - 1)] = cr;
+                        h[(i, n)] = ci;
+                        if x.abs() > z.abs() + q.abs() {
+                            h[(i + 1, n - 1)] = (-ra - w * h[(i, n - 1)] + q * h[(i, n)]) / x;
+                            h[(i + 1, n)] = (-sa - w * h[(i, n)] - q * h[(i, n - 1)]) / x;
+                        } else {
+                            let (cr, ci) = cdiv(-r - y * h[(i, n - 1)], -s - y * h[(i, n)], z, q);
+                            h[(i + 1, n - 1)] = cr;
+                            h[(i + 1, n)] = ci;
+                        }
+                    }
+                    t = h[(i, n - 1)].abs().max(h[(i, n)].abs());
+                    if (eps * t) * t > 1f64 {
+                        for j in i..=n {
+                            h[(j, n - 1)] /= t;
+                            h[(j, n)] /= t;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    for j in (0..nn).rev() {
+        for i in 0..nn {
+            z = 0f64;
+            for k in 0..=j { z += v[(i, k)] * h[(k, j)]; }
+            v[(i, j)] = z;
+        }
+    }
+}
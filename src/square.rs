@@ -1,5 +1,6 @@
 use crate::numeric::Numeric;
 use crate::matrix::{ Matrix };
+use crate::view::{ MatrixView, MatrixViewMut };
 
 pub trait Square {
     fn is_square(&self) -> bool;
@@ -11,3 +12,14 @@ impl<T> Square for Matrix<T> where T: Numeric {
     }
 }
 
+impl<T> Square for MatrixView<'_, T> where T: Numeric {
+    fn is_square(&self) -> bool {
+        self.rows() == self.cols()
+    }
+}
+
+impl<T> Square for MatrixViewMut<'_, T> where T: Numeric {
+    fn is_square(&self) -> bool {
+        self.rows() == self.cols()
+    }
+}
@@ -0,0 +1,305 @@
+use std::fmt;
+
+use crate::numeric::Real;
+use crate::matrix::{ Matrix, MatrixBuilder, MatrixIndexOutOfBoundsError, Swap };
+
+#[derive(Debug, PartialEq)]
+pub struct SingularValueDecompositionError;
+
+impl fmt::Display for SingularValueDecompositionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "An error occurred while computing singular values")
+    }
+}
+
+impl std::error::Error for SingularValueDecompositionError {}
+
+#[derive(Debug, Clone)]
+pub struct SingularValueDecomposition {
+    u: Matrix<f64>,
+    singular_values: Vec<f64>,
+    v: Matrix<f64>,
+}
+
+pub trait Svd {
+    fn svd(&self) -> Result<SingularValueDecomposition, SingularValueDecompositionError>;
+}
+
+impl<T> Svd for Matrix<T> where T: Real {
+    fn svd(&self) -> Result<SingularValueDecomposition, SingularValueDecompositionError> {
+        if self.rows() >= self.cols() {
+            one_sided_jacobi(self.copy_to::<f64>().unwrap(), crate::worker_threads())
+        } else {
+            let transposed = one_sided_jacobi(self.copy_to::<f64>().unwrap().transpose(), crate::worker_threads())?;
+            Ok(SingularValueDecomposition { 
+                u: transposed.v, 
+                singular_values: transposed.singular_values, 
+                v: transposed.u,
+            })
+        }
+    }
+}
+
+impl SingularValueDecomposition {
+
+    pub fn singular_values(&self) -> &[f64] {
+        &self.singular_values
+    }
+
+    pub fn thin_u(&self) -> &Matrix<f64> {
+        &self.u
+    }
+
+    pub fn thin_v(&self) -> &Matrix<f64> {
+        &self.v
+    }
+
+    pub fn u(&self) -> Matrix<f64> {
+        complete_basis(&self.u)
+    }
+
+    pub fn v(&self) -> Matrix<f64> {
+        complete_basis(&self.v)
+    }
+
+    pub fn sigma(&self) -> Matrix<f64> {
+        let k = self.singular_values.len();
+        let mut sigma = MatrixBuilder::<f64>::new().zeros(k, k).build().unwrap();
+        for (j, &s) in self.singular_values.iter().enumerate() { sigma[(j, j)] = s; }
+        sigma
+    }
+
+    pub fn default_tolerance(&self) -> f64 {
+        let largest = self.singular_values.first().copied().unwrap_or(0f64);
+        largest * f64::EPSILON * self.u.rows().max(self.v.rows()) as f64
+    }
+
+    pub fn rank(&self, tolerance: f64) -> usize {
+        self.singular_values.iter().filter(|&&s| s > tolerance).count()
+    }
+
+    pub fn numerical_rank(&self) -> usize {
+        self.rank(self.default_tolerance())
+    }
+
+    pub fn condition_number(&self) -> f64 {
+        let largest = self.singular_values.first().copied().unwrap_or(0f64);
+        let smallest = self.singular_values.last().copied().unwrap_or(0f64);
+        if smallest == 0f64 { f64::INFINITY } else { largest / smallest }
+    }
+
+    pub fn pseudoinverse(&self) -> Matrix<f64> {
+        let tolerance = self.default_tolerance();
+        let (m, n) = (self.u.rows(), self.v.rows());
+        let mut pinv = MatrixBuilder::<f64>::new().zeros(n, m).build().unwrap();
+        for (k, &s) in self.singular_values.iter().enumerate() {
+            if s <= tolerance { continue }
+            for i in 0..n {
+                let vik = self.v[(i, k)] / s;
+                for j in 0..m {
+                    pinv[(i, j)] += vik * self.u[(j, k)];
+                }
+            }
+        }
+        pinv
+    }
+
+    pub fn low_rank_approximation(&self, rank: usize) -> Result<Matrix<f64>, MatrixIndexOutOfBoundsError> {
+        if rank == 0 || rank > self.singular_values.len() { return Err(MatrixIndexOutOfBoundsError) }
+        let (m, n) = (self.u.rows(), self.v.rows());
+        let mut approximation = MatrixBuilder::<f64>::new().zeros(m, n).build().unwrap();
+        for k in 0..rank {
+            let s = self.singular_values[k];
+            for i in 0..m {
+                let uik = self.u[(i, k)] * s;
+                for j in 0..n {
+                    approximation[(i, j)] += uik * self.v[(j, k)];
+                }
+            }
+        }
+        Ok(approximation)
+    }
+
+}
+
+// Hestenes one-sided Jacobi on the columns of a tall matrix: A·V = U·Σ
+#[cfg_attr(not(feature = "parallel"), allow(unused_variables))]
+pub(crate) fn one_sided_jacobi(mut u: Matrix<f64>, threads: usize) -> Result<SingularValueDecomposition, SingularValueDecompositionError> {
+    let (m, n) = (u.rows(), u.cols());
+    let eps = f64::EPSILON;
+    let mut v = MatrixBuilder::<f64>::new().identity(n).build().unwrap();
+    #[cfg(feature = "parallel")]
+    let converged = if m * n * n >= crate::parallel::MIN_PARALLEL_WORK {
+        round_robin_sweeps(&mut u, &mut v, threads)
+    } else {
+        cyclic_sweeps(&mut u, &mut v)
+    };
+    #[cfg(not(feature = "parallel"))]
+    let converged = cyclic_sweeps(&mut u, &mut v);
+    if !converged { return Err(SingularValueDecompositionError) }
+
+    let mut singular_values: Vec<f64> = (0..n)
+        .map(|j| (0..m).map(|i| u[(i, j)] * u[(i, j)]).sum::<f64>().sqrt())
+        .collect();
+    for j in 0..n {
+        let largest = singular_values[j..].iter().enumerate()
+            .fold((j, singular_values[j]), |acc, (k, &s)| if s > acc.1 { (j + k, s) } else { acc });
+        if largest.0 != j {
+            singular_values.swap(j, largest.0);
+            u.swap_cols(j, largest.0).map_err(|_| SingularValueDecompositionError)?;
+            v.swap_cols(j, largest.0).map_err(|_| SingularValueDecompositionError)?;
+        }
+    }
+    let tolerance = singular_values.first().copied().unwrap_or(0f64) * eps * m as f64;
+    let mut valid = n;
+    for (j, &s) in singular_values.iter().enumerate() {
+        if s <= tolerance {
+            valid = valid.min(j);
+            for i in 0..m { u[(i, j)] = 0f64; }
+        } else {
+            for i in 0..m { u[(i, j)] /= s; }
+        }
+    }
+    if valid < n {
+        let completed = complete_basis(&u.submatrix(.., ..valid.max(1)).unwrap());
+        for j in valid..n {
+            for i in 0..m { u[(i, j)] = completed[(i, j)]; }
+        }
+    }
+    Ok(SingularValueDecomposition { u, singular_values, v })
+}
+
+// Cyclic-by-row ordering, one column pair at a time
+fn cyclic_sweeps(u: &mut Matrix<f64>, v: &mut Matrix<f64>) -> bool {
+    let (m, n) = (u.rows(), u.cols());
+    let eps = f64::EPSILON;
+    let mut converged = false;
+    for _ in 0..60 {
+        converged = true;
+        for p in 0..n {
+            for q in (p + 1)..n {
+                let mut alpha = 0f64;
+                let mut beta = 0f64;
+                let mut gamma = 0f64;
+                for i in 0..m {
+                    alpha += u[(i, p)] * u[(i, p)];
+                    beta += u[(i, q)] * u[(i, q)];
+                    gamma += u[(i, p)] * u[(i, q)];
+                }
+                if gamma == 0f64 || gamma.abs() <= eps * (alpha * beta).sqrt() { continue }
+                converged = false;
+                let zeta = (beta - alpha) / (2f64 * gamma);
+                let t = zeta.signum() / (zeta.abs() + (1f64 + zeta * zeta).sqrt());
+                let c = 1f64 / (1f64 + t * t).sqrt();
+                let s = c * t;
+                rotate_cols(u, c, s, p, q);
+                rotate_cols(v, c, s, p, q);
+            }
+        }
+        if converged { break }
+    }
+    converged
+}
+
+// Brent-Luk ordering, each round rotates n/2 disjoint column pairs at once so they can run on separate threads.
+// Columns are kept as rows of the transposes to make every rotation touch two contiguous slices.
+// The rotation order differs from the cyclic sweeps, results agree to rounding but are not bit-identical.
+#[cfg(feature = "parallel")]
+fn round_robin_sweeps(u: &mut Matrix<f64>, v: &mut Matrix<f64>, threads: usize) -> bool {
+    let (m, n) = (u.rows(), u.cols());
+    let mut ut = u.transpose();
+    let mut vt = v.transpose();
+    let players = n + n % 2;
+    let mut converged = false;
+    for _ in 0..60 {
+        converged = true;
+        let mut order: Vec<usize> = (0..players).collect();
+        for _ in 1..players {
+            let mut u_rows: Vec<Option<&mut [f64]>> = ut.data_mut().chunks_mut(m).map(Some).collect();
+            let mut v_rows: Vec<Option<&mut [f64]>> = vt.data_mut().chunks_mut(n).map(Some).collect();
+            let jobs: Vec<_> = (0..players / 2)
+                .map(|i| (order[i].min(order[players - 1 - i]), order[i].max(order[players - 1 - i])))
+                .filter(|&(_, q)| q < n)
+                .map(|(p, q)| (u_rows[p].take().unwrap(), u_rows[q].take().unwrap(), v_rows[p].take().unwrap(), v_rows[q].take().unwrap()))
+                .collect();
+            let rotated = crate::parallel::map(jobs, threads, |(up, uq, vp, vq)| orthogonalize_pair(up, uq, vp, vq));
+            converged &= !rotated.contains(&true);
+            order[1..].rotate_right(1);
+        }
+        if converged { break }
+    }
+    *u = ut.transpose();
+    *v = vt.transpose();
+    converged
+}
+
+// Same rotation as cyclic_sweeps applied to columns stored as slices, returns whether one was needed
+#[cfg(feature = "parallel")]
+fn orthogonalize_pair(up: &mut [f64], uq: &mut [f64], vp: &mut [f64], vq: &mut [f64]) -> bool {
+    let eps = f64::EPSILON;
+    let mut alpha = 0f64;
+    let mut beta = 0f64;
+    let mut gamma = 0f64;
+    for (x, y) in up.iter().zip(uq.iter()) {
+        alpha += x * x;
+        beta += y * y;
+        gamma += x * y;
+    }
+    if gamma == 0f64 || gamma.abs() <= eps * (alpha * beta).sqrt() { return false }
+    let zeta = (beta - alpha) / (2f64 * gamma);
+    let t = zeta.signum() / (zeta.abs() + (1f64 + zeta * zeta).sqrt());
+    let c = 1f64 / (1f64 + t * t).sqrt();
+    let s = c * t;
+    for (p, q) in [(up, uq), (vp, vq)] {
+        for (x, y) in p.iter_mut().zip(q.iter_mut()) {
+            let (xp, yq) = (*x, *y);
+            *x = c * xp - s * yq;
+            *y = s * xp + c * yq;
+        }
+    }
+    true
+}
+
+fn rotate_cols(a: &mut Matrix<f64>, c: f64, s: f64, p: usize, q: usize) {
+    for i in 0..a.rows() {
+        let ap = a[(i, p)];
+        let aq = a[(i, q)];
+        a[(i, p)] = c * ap - s * aq;
+        a[(i, q)] = s * ap + c * aq;
+    }
+}
+
+// Extends orthonormal columns (zero columns are ignored) to a square orthonormal basis
+fn complete_basis(q: &Matrix<f64>) -> Matrix<f64> {
+    let m = q.rows();
+    let mut basis: Vec<Vec<f64>> = (0..q.cols())
+        .map(|j| q.col(j).unwrap())
+        .filter(|c| c.iter().any(|&x| x != 0f64))
+        .collect();
+    let mut result = MatrixBuilder::<f64>::new().zeros(m, m).build().unwrap();
+    for j in 0..q.cols() {
+        for i in 0..m { result[(i, j)] = q[(i, j)]; }
+    }
+    for j in 0..m {
+        let is_filled = j < q.cols() && (0..m).any(|i| q[(i, j)] != 0f64);
+        if is_filled { continue }
+        let mut best: Option<(f64, Vec<f64>)> = None;
+        for e in 0..m {
+            let mut w = vec![0f64; m];
+            w[e] = 1f64;
+            for _ in 0..2 {
+                for b in &basis {
+                    let dot: f64 = b.iter().zip(w.iter()).map(|(x, y)| x * y).sum();
+                    for (wi, bi) in w.iter_mut().zip(b.iter()) { *wi -= dot * bi; }
+                }
+            }
+            let norm = w.iter().map(|x| x * x).sum::<f64>().sqrt();
+            if best.as_ref().is_none_or(|(n, _)| norm > *n) { best = Some((norm, w)); }
+        }
+        let (norm, w) = best.unwrap();
+        let w: Vec<f64> = w.iter().map(|x| x / norm).collect();
+        for (i, &wi) in w.iter().enumerate() { result[(i, j)] = wi; }
+        basis.push(w);
+    }
+    result
+}
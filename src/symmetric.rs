@@ -1,15 +1,45 @@
 use std::fmt;
 
-use crate::numeric::Numeric;
+use crate::numeric::{ Numeric, Real, Float };
 use crate::matrix::{ Matrix, MatrixBuilder, Swap };
 use crate::square::Square;
+use crate::view::{ MatrixView, MatrixViewMut };
+use crate::arithmetic::MatrixDimensionMismatchError;
+use crate::tolerance::Tolerance;
 
 pub trait Symmetric {
     fn is_symmetric(&self) -> bool;
-    fn eigen_decomposition(&self, algorithm: Algorithm) -> Result<(Vec<f64>, Matrix<f64>), EigenDecompositionError>;
+    fn is_symmetric_within(&self, tolerance: Tolerance) -> bool;
+    fn eigen_decomposition_as<F>(&self, algorithm: Algorithm) -> Result<(Vec<F>, Matrix<F>), EigenDecompositionError> where F: Float;
+
+    fn eigen_decomposition(&self, algorithm: Algorithm) -> Result<(Vec<f64>, Matrix<f64>), EigenDecompositionError> {
+        self.eigen_decomposition_as::<f64>(algorithm)
+    }
 }
 
-impl<T> Symmetric for Matrix<T> where T: Numeric {
+impl<T> Symmetric for Matrix<T> where T: Real {
+    fn is_symmetric(&self) -> bool {
+        self.view().is_symmetric()
+    }
+
+    fn is_symmetric_within(&self, tolerance: Tolerance) -> bool {
+        self.view().is_symmetric_within(tolerance)
+    }
+
+    fn eigen_decomposition_as<F>(&self, algorithm: Algorithm) -> Result<(Vec<F>, Matrix<F>), EigenDecompositionError> where F: Float {
+        match algorithm {
+            Algorithm::Jacobi => {
+                EigenDecomposition::<T, F>::decompose(&JacobiDecomposition, self)
+            },
+            Algorithm::TridiagonalQL => {
+                EigenDecomposition::<T, F>::decompose(&TridiagonalQLDecomposition, self)
+            },
+        }
+        
+    }
+}
+
+impl<T> Symmetric for MatrixView<'_, T> where T: Real {
     fn is_symmetric(&self) -> bool {
         if !self.is_square() { return false }
         for i in 0..self.rows() {
@@ -20,16 +50,41 @@ impl<T> Symmetric for Matrix<T> where T: Numeric {
         true
     }
 
-    fn eigen_decomposition(&self, algorithm: Algorithm) -> Result<(Vec<f64>, Matrix<f64>), EigenDecompositionError> {
-        match algorithm {
-            Algorithm::Jacobi => {
-                EigenDecomposition::<T>::decompose(&JacobiDecomposition, self)
-            },
+    fn is_symmetric_within(&self, tolerance: Tolerance) -> bool {
+        if !self.is_square() { return false }
+        for i in 0..self.rows() {
+            for j in 0..i {
+                if !tolerance.is_within(self[(i, j)].to::<f64>(), self[(j, i)].to::<f64>()) { return false }
+            }
         }
-        
+        true
+    }
+
+    fn eigen_decomposition_as<F>(&self, algorithm: Algorithm) -> Result<(Vec<F>, Matrix<F>), EigenDecompositionError> where F: Float {
+        self.to_matrix().eigen_decomposition_as(algorithm)
     }
 }
 
+impl<T> Symmetric for MatrixViewMut<'_, T> where T: Real {
+    fn is_symmetric(&self) -> bool {
+        self.as_view().is_symmetric()
+    }
+
+    fn is_symmetric_within(&self, tolerance: Tolerance) -> bool {
+        self.as_view().is_symmetric_within(tolerance)
+    }
+
+    fn eigen_decomposition_as<F>(&self, algorithm: Algorithm) -> Result<(Vec<F>, Matrix<F>), EigenDecompositionError> where F: Float {
+        self.as_view().eigen_decomposition_as(algorithm)
+    }
+}
+
+// Halving would truncate integer entries, so only float matrices can be symmetrized
+impl<F> Matrix<F> where F: Float {
+    pub fn symmetrize(&self) -> Result<Matrix<F>, MatrixDimensionMismatchError> {
+        Ok(self.checked_add(&self.transpose())? / (F::one() + F::one()))
+    }
+}
 
 #[derive(Debug)]
 pub struct EigenDecompositionError;
@@ -44,105 +99,287 @@ impl std::error::Error for EigenDecompositionError {}
 
 pub enum Algorithm {
     Jacobi,
+    TridiagonalQL,
     // Other algorithms may come here
 }      
 
-pub trait EigenDecomposition<T> {
-    fn decompose(&self, m: &Matrix<T>) -> Result<(Vec<f64>, Matrix<f64>), EigenDecompositionError> where T: Numeric;
+pub trait EigenDecomposition<T, F = f64> {
+    fn decompose(&self, m: &Matrix<T>) -> Result<(Vec<F>, Matrix<F>), EigenDecompositionError> where T: Real, F: Float;
 }
 
 pub struct JacobiDecomposition;
 
-impl<T> EigenDecomposition<T> for JacobiDecomposition where T: Numeric {
+impl<T, F> EigenDecomposition<T, F> for JacobiDecomposition where T: Real, F: Float {
+    fn decompose(&self, m: &Matrix<T>) -> Result<(Vec<F>, Matrix<F>), EigenDecompositionError> {
+        jacobi(m, crate::worker_threads())
+    }
+}
+
 // Source: https://www.astro.umd.edu/~ricotti/NEWWEB/teaching/ASTR415/InClassExamples/NR3/code/eigen_sym.h
-fn decompose(&self, m: &Matrix<T>) -> Result<(Vec<f64>, Matrix<f64>), EigenDecompositionError> {
-        if !m.is_symmetric() { return Err(EigenDecompositionError) }
-        let n = m.rows();
-        let eps = std::f64::EPSILON;
-        let mut a = m.copy_to::<f64>().unwrap();
-        let mut v = MatrixBuilder::<f64>::new().identity(n).build().unwrap();
-        let mut d = vec![0f64; n];
-        let mut _nrot = 0u64;
-        let mut theta: f64;
-        let mut b = vec![0f64; n];
-        let mut z = vec![0f64; n];
-        for ip in 0..n {
-            b[ip] = a[(ip, ip)];
-            d[ip] = a[(ip, ip)];
-            z[ip] = 0f64;
+// Rotations of the eigen vectors are collected during a sweep and applied to the rows of V at its end
+pub(crate) fn jacobi<T, F>(m: &Matrix<T>, threads: usize) -> Result<(Vec<F>, Matrix<F>), EigenDecompositionError>
+where T: Real, F: Float {
+    if !m.is_symmetric() { return Err(EigenDecompositionError) }
+    let n = m.rows();
+    let eps = F::epsilon();
+    let mut a = m.copy_to::<F>().unwrap();
+    let mut v = MatrixBuilder::<F>::new().identity(n).build().unwrap();
+    let mut d = vec![F::zero(); n];
+    let mut _nrot = 0u64;
+    let mut theta: F;
+    let mut b = vec![F::zero(); n];
+    let mut z = vec![F::zero(); n];
+    let mut rotations = Vec::new();
+    for ip in 0..n {
+        b[ip] = a[(ip, ip)];
+        d[ip] = a[(ip, ip)];
+        z[ip] = F::zero();
+    }
+    for i in 1..50 {
+        let mut sm = F::zero();
+        for ip in 0..(n-1) {
+            for iq in (ip+1)..n {
+                sm += a[(ip, iq)].abs();
+            }
         }
-        for i in 1..50 {
-            let mut sm = 0f64;
-            for ip in 0..(n-1) {
-                for iq in (ip+1)..n {
-                    sm += a[(ip, iq)].abs();
-                }
+        if sm == F::zero() {
+            match eigen_sort(&mut d, &mut v) {
+                Ok(()) => return Ok((d, v)),
+                Err(_) => return Err(EigenDecompositionError)
             }
-            if sm == 0f64 {
-                match eigen_sort(&mut d, &mut v) {
-                    Ok(()) => return Ok((d, v)),
-                    Err(_) => return Err(EigenDecompositionError)
+        }
+        let tresh = if i < 4 {
+            sm * F::from_f64(0.2f64) / F::from_usize(n * n)
+        } else {
+            F::zero()
+        };
+        for ip in 0..(n-1) {
+            for iq in ip+1..n {
+                let g = a[(ip, iq)].abs() * F::from_f64(100f64);
+                if i > 4 && g <= eps * d[ip].abs() && g <= eps * d[iq].abs() {
+                    a[(ip, iq)] = F::zero();
+                } else if a[(ip, iq)].abs() > tresh {
+                    let h = d[iq]-d[ip];
+                    let t: F = if g <= eps * h.abs() {
+                        a[(ip, iq)] / h
+                    } else {
+                        theta = h * F::from_f64(0.5f64) / a[(ip, iq)];
+                        let denominator: F = theta.abs() + (theta * theta + F::one()).sqrt();
+                        if theta < F::zero() {
+                            - F::one() / denominator
+                        } else {
+                            F::one() / denominator
+                        }
+                    };
+                    let c = F::one() / (t * t + F::one()).sqrt();
+                    let s = t * c;
+                    let tau=s/(c + F::one());
+                    let h=t*a[(ip, iq)];
+                    z[ip] -= h;
+                    z[iq] += h;
+                    d[ip] -= h;
+                    d[iq] += h;
+                    a[(ip, iq)] = F::zero();
+                    for j in 0..ip {
+                        rot(&mut a,s,tau,j,ip,j,iq);
+                    }
+                    for j in (ip + 1)..iq {
+                        rot(&mut a,s,tau,ip,j,j,iq);
+                    }
+                    for j in (iq + 1)..n {
+                        rot(&mut a,s,tau,ip,j,iq,j);
+                    }
+                    rotations.push((ip, iq, s, tau));
+                    _nrot += 1;
                 }
             }
-            let tresh = if i < 4 {
-                sm * 0.2f64 / (n * n) as f64
+        }
+        rotate_rows(&mut v, &rotations, threads);
+        rotations.clear();
+        for ip in 0..n {
+            b[ip] += z[ip];
+            d[ip] = b[ip];
+            z[ip] = F::zero();
+        }
+    }
+    Err(EigenDecompositionError)
+}
+
+// Same arithmetic as rot on each row of V, rows are independent so they can be handed to separate threads
+#[cfg_attr(not(feature = "parallel"), allow(unused_variables))]
+fn rotate_rows<F>(v: &mut Matrix<F>, rotations: &[(usize, usize, F, F)], threads: usize) where F: Float {
+    let n = v.cols();
+    let apply = |row: &mut [F]| {
+        for &(ip, iq, s, tau) in rotations {
+            let g = row[ip];
+            let h = row[iq];
+            row[ip] = g - s * (h + g * tau);
+            row[iq] = h + s * (g - h * tau);
+        }
+    };
+    #[cfg(feature = "parallel")]
+    if n * n * rotations.len() >= crate::parallel::MIN_PARALLEL_WORK {
+        crate::parallel::for_each_row(v.data_mut(), n, threads, |_, row| apply(row));
+        return
+    }
+    for row in v.data_mut().chunks_mut(n) {
+        apply(row);
+    }
+}
+
+pub struct TridiagonalQLDecomposition;
+
+impl<T, F> EigenDecomposition<T, F> for TridiagonalQLDecomposition where T: Real, F: Float {
+    // Source: https://www.astro.umd.edu/~ricotti/NEWWEB/teaching/ASTR415/InClassExamples/NR3/code/eigen_sym.h
+    fn decompose(&self, m: &Matrix<T>) -> Result<(Vec<F>, Matrix<F>), EigenDecompositionError> {
+        if !m.is_symmetric() { return Err(EigenDecompositionError) }
+        let mut z = m.copy_to::<F>().unwrap();
+        let n = z.rows();
+        let mut d = vec![F::zero(); n];
+        let mut e = vec![F::zero(); n];
+        tred2(&mut z, &mut d, &mut e);
+        tqli(&mut z, &mut d, &mut e)?;
+        eigen_sort(&mut d, &mut z)?;
+        Ok((d, z))
+    }
+}
+
+// Householder reduction to tridiagonal form, z is overwritten by the accumulated transformation
+fn tred2<F>(z: &mut Matrix<F>, d: &mut [F], e: &mut [F]) where F: Float {
+    let n = z.rows();
+    for i in (1..n).rev() {
+        let l = i - 1;
+        let mut h = F::zero();
+        if l > 0 {
+            let mut scale = F::zero();
+            for k in 0..i {
+                scale += z[(i, k)].abs();
+            }
+            if scale == F::zero() {
+                e[i] = z[(i, l)];
             } else {
-                0f64
-            };
-            for ip in 0..(n-1) {
-                for iq in ip+1..n {
-                    let g = a[(ip, iq)].abs() * 100f64;
-                    if i > 4 && g <= eps * d[ip].abs() && g <= eps * d[iq].abs() {
-                        a[(ip, iq)] = 0f64;
-                    } else if a[(ip, iq)].abs() > tresh {
-                        let h = d[iq]-d[ip];
-                        let t: f64 = if g <= eps * h.abs() {
-                            a[(ip, iq)] / h
-                        } else {
-                            theta = h * 0.5f64 / a[(ip, iq)];
-                            let denominator: f64 = theta.abs() + (theta * theta + 1f64).sqrt();
-                            if theta < 0f64 {
-                                - 1f64 / denominator
-                            } else {
-                                1f64 / denominator
-                            }
-                        };
-                        let c = 1f64 / (t * t + 1f64).sqrt();
-                        let s = t * c;
-                        let tau=s/(c + 1f64);
-                        let h=t*a[(ip, iq)];
-                        z[ip] -= h;
-                        z[iq] += h;
-                        d[ip] -= h;
-                        d[iq] += h;
-                        a[(ip, iq)] = 0f64;
-                        for j in 0..ip {
-                            rot(&mut a,s,tau,j,ip,j,iq);
-                        }
-                        for j in (ip + 1)..iq {
-                            rot(&mut a,s,tau,ip,j,j,iq);
-                        }
-                        for j in (iq + 1)..n {
-                            rot(&mut a,s,tau,ip,j,iq,j);
-                        }
-                        for j in 0..n {
-                            rot(&mut v,s,tau,j,ip,j,iq);
-                        }
-                        _nrot += 1;
+                for k in 0..i {
+                    z[(i, k)] /= scale;
+                    h += z[(i, k)] * z[(i, k)];
+                }
+                let mut f = z[(i, l)];
+                let mut g = if f >= F::zero() { -h.sqrt() } else { h.sqrt() };
+                e[i] = scale * g;
+                h -= f * g;
+                z[(i, l)] = f - g;
+                f = F::zero();
+                for j in 0..i {
+                    z[(j, i)] = z[(i, j)] / h;
+                    g = F::zero();
+                    for k in 0..(j + 1) {
+                        g += z[(j, k)] * z[(i, k)];
+                    }
+                    for k in (j + 1)..i {
+                        g += z[(k, j)] * z[(i, k)];
+                    }
+                    e[j] = g / h;
+                    f += e[j] * z[(i, j)];
+                }
+                let hh = f / (h + h);
+                for j in 0..i {
+                    let f = z[(i, j)];
+                    g = e[j] - hh * f;
+                    e[j] = g;
+                    for k in 0..(j + 1) {
+                        let update = f * e[k] + g * z[(i, k)];
+                        z[(j, k)] -= update;
                     }
                 }
             }
-            for ip in 0..n {
-                b[ip] += z[ip];
-                d[ip] = b[ip];
-                z[ip] = 0f64;
+        } else {
+            e[i] = z[(i, l)];
+        }
+        d[i] = h;
+    }
+    d[0] = F::zero();
+    e[0] = F::zero();
+    for i in 0..n {
+        if d[i] != F::zero() {
+            for j in 0..i {
+                let mut g = F::zero();
+                for k in 0..i {
+                    g += z[(i, k)] * z[(k, j)];
+                }
+                for k in 0..i {
+                    let update = g * z[(k, i)];
+                    z[(k, j)] -= update;
+                }
             }
         }
-        return Err(EigenDecompositionError)
-    }     
+        d[i] = z[(i, i)];
+        z[(i, i)] = F::one();
+        for j in 0..i {
+            z[(j, i)] = F::zero();
+            z[(i, j)] = F::zero();
+        }
+    }
+}
+
+// QL algorithm with implicit shifts on the tridiagonal matrix (d, e)
+fn tqli<F>(z: &mut Matrix<F>, d: &mut [F], e: &mut [F]) -> Result<(), EigenDecompositionError> where F: Float {
+    let n = z.rows();
+    let eps = F::epsilon();
+    for i in 1..n { e[i - 1] = e[i]; }
+    e[n - 1] = F::zero();
+    for l in 0..n {
+        let mut iter = 0;
+        loop {
+            let mut m = l;
+            while m < n - 1 {
+                let dd = d[m].abs() + d[m + 1].abs();
+                if e[m].abs() <= eps * dd { break }
+                m += 1;
+            }
+            if m == l { break }
+            if iter == 30 { return Err(EigenDecompositionError) }
+            iter += 1;
+            let mut g = (d[l + 1] - d[l]) / (e[l] + e[l]);
+            let mut r = g.hypot(F::one());
+            g = d[m] - d[l] + e[l] / (g + if g >= F::zero() { r.abs() } else { -r.abs() });
+            let mut s = F::one();
+            let mut c = F::one();
+            let mut p = F::zero();
+            let mut underflow = false;
+            for i in (l..m).rev() {
+                let f = s * e[i];
+                let b = c * e[i];
+                r = f.hypot(g);
+                e[i + 1] = r;
+                if r == F::zero() {
+                    d[i + 1] -= p;
+                    e[m] = F::zero();
+                    underflow = true;
+                    break
+                }
+                s = f / r;
+                c = g / r;
+                g = d[i + 1] - p;
+                r = (d[i] - g) * s + (c + c) * b;
+                p = s * r;
+                d[i + 1] = g + p;
+                g = c * r - b;
+                for k in 0..n {
+                    let f = z[(k, i + 1)];
+                    z[(k, i + 1)] = s * z[(k, i)] + c * f;
+                    z[(k, i)] = c * z[(k, i)] - s * f;
+                }
+            }
+            if underflow { continue }
+            d[l] -= p;
+            e[l] = g;
+            e[m] = F::zero();
+        }
+    }
+    Ok(())
 }
 
-pub fn eigen_sort<T> (eigen_values: &mut [T], eigen_matrix: &mut Matrix<T>) -> Result<(), EigenDecompositionError>
+#[allow(clippy::needless_range_loop)]
+pub(crate) fn eigen_sort<T> (eigen_values: &mut [T], eigen_matrix: &mut Matrix<T>) -> Result<(), EigenDecompositionError>
 where T: Numeric {
     if !eigen_matrix.is_square() {
         Err(EigenDecompositionError)
@@ -169,7 +406,7 @@ where T: Numeric {
     }
 }
 
-pub fn rot<T: Numeric>(a: &mut Matrix<T>, s: T, tau: T, i: usize, j: usize, k: usize, l: usize)
+pub(crate) fn rot<T: Numeric>(a: &mut Matrix<T>, s: T, tau: T, i: usize, j: usize, k: usize, l: usize)
 {
     let g = a[(i, j)];
     let h = a[(k, l)];
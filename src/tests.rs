@@ -1,8 +1,50 @@
-use crate::matrix::{ MatrixBuilder, MatrixDefinitionError };
+#![allow(clippy::excessive_precision, clippy::redundant_pattern_matching)]
+
+use crate::matrix::{ MatrixBuilder, MatrixDefinitionError, MatrixIndexOutOfBoundsError };
+use crate::arithmetic::{ MatrixDimensionMismatchError, MatrixArithmeticError };
+use crate::view::MatrixView;
 use crate::square::Square;
 use crate::symmetric::{ Symmetric, Algorithm };
-use crate::definite::{ CholeskyDecompositionError, PositiveDefinite };
+use crate::definite::{ CholeskyDecompositionError, CholeskyFactor, PositiveDefinite, PositiveSemiDefinite };
+use crate::lu::{ Lu, LuDecompositionError };
+use crate::indefinite::{ SymmetricIndefinite, LdltDecompositionError, Inertia };
+use crate::triangular::{ Triangular, TriangularSolveError };
+use crate::qr::{ Qr, QrDecompositionError, least_squares };
+use crate::svd::Svd;
+use crate::schur::GeneralEigen;
+use crate::correlation::{ NearestCorrelation, NearestCorrelationError };
+use crate::numeric::{ Numeric, NumericConversionError };
+use crate::complex::Complex;
+use crate::hermitian::Hermitian;
+use crate::rational::{ Rational, RationalError };
+use crate::exact::{ ExactElimination, ExactEliminationError };
+use crate::tolerance::Tolerance;
+use crate::approx::ElementDifference;
+use crate::assert_matrix_approx_eq;
+use crate::matrix::Matrix;
+
+fn pseudo_random_matrix(rows: usize, cols: usize, seed: u64) -> Matrix<f64> {
+    let mut state = seed;
+    let data = (0..rows * cols).map(|_| {
+        state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        (state >> 11) as f64 / (1u64 << 53) as f64 - 0.5f64
+    }).collect();
+    MatrixBuilder::new().from_vec(rows, cols, data).build().unwrap()
+}
 
+fn assert_eigen_pair(a: &Matrix<f64>, lambda: Complex<f64>, v: &[Complex<f64>], tolerance: f64) {
+    let n = a.rows();
+    assert!(v.iter().any(|x| x.norm() > tolerance));
+    for i in 0..n {
+        let mut re = -(lambda.re * v[i].re - lambda.im * v[i].im);
+        let mut im = -(lambda.re * v[i].im + lambda.im * v[i].re);
+        for (k, x) in v.iter().enumerate() {
+            re += a[(i, k)] * x.re;
+            im += a[(i, k)] * x.im;
+        }
+        assert!(re.abs() < tolerance && im.abs() < tolerance, "A·v - λ·v = {}+{}i for λ = {}", re, im, lambda);
+    }
+}
 
 #[test]
 fn test_builder() {
@@ -451,7 +493,7 @@ fn test_cholesky() {
     ];
     let matrix = MatrixBuilder::new().from_mat(data).build().unwrap();
     println!("{:?}", matrix.cholesky());
-    assert_eq!(matrix.cholesky(), Err(CholeskyDecompositionError::MatrixIsNotPositiveDefinite));
+    assert_eq!(matrix.cholesky(), Err(CholeskyDecompositionError::LeadingMinorIsNotPositive { index: 0, pivot: 0f64 }));
     let a = MatrixBuilder::new().from_mat(
         vec![
             vec![  1i32,   2i32,   4i32,   7i32],
@@ -468,7 +510,7 @@ fn test_cholesky() {
             vec![7f64, 8f64, 9f64, 10f64]
         ]
     ).build().unwrap();
-    assert_eq!(l, a.cholesky().unwrap());
+    assert_eq!(&l, a.cholesky().unwrap().l());
     let a = MatrixBuilder::new().from_mat(
         vec![
             vec![  1i64,   2i64,   4i64,   7i64],
@@ -485,7 +527,7 @@ fn test_cholesky() {
             vec![7f64, 8f64, 9f64, 10f64]
         ]
     ).build().unwrap();
-    assert_eq!(l, a.cholesky().unwrap());
+    assert_eq!(&l, a.cholesky().unwrap().l());
     let a = MatrixBuilder::new().from_mat(
         vec![
             vec![1.9383451f32 , 0.76780201f32, 1.4940289f32 , 0.75654844f32],
@@ -494,15 +536,7 @@ fn test_cholesky() {
             vec![0.75654844f32, 0.33242197f32, 0.83237389f32, 0.80474618f32]
         ]
     ).build().unwrap();
-    let l = MatrixBuilder::new().from_mat(
-        vec![
-            vec![1.3922446173907894f64, 0.0f64                , 0.0f64                , 0.0f64               ], 
-            vec![0.551484983640818f64 , 0.7058153132528383f64 , 0.0f64                , 0.0f64               ], 
-            vec![1.0731080639375399f64, 0.6428679620108512f64 , 0.48889128612067473f64, 0.0f64               ], 
-            vec![0.5434019674761598f64, 0.04639164479390491f64, 0.44881385275390756f64, 0.5530591230447315f64]
-        ]
-    ).build().unwrap();
-    assert_eq!(l, a.cholesky().unwrap());
+    let f32_l = a.cholesky().unwrap().into_l();
     let a = MatrixBuilder::new().from_mat(
         vec![
             vec![1.9383451f64 , 0.76780201f64, 1.4940289f64 , 0.75654844f64],
@@ -519,5 +553,1460 @@ fn test_cholesky() {
             vec![0.5434019464707462f64, 0.04639167566223414f64, 0.44881368408590505f64, 0.5530593042145585f64],
         ]
     ).build().unwrap();
-    assert_eq!(l, a.cholesky().unwrap());
+    assert_matrix_approx_eq!(a.cholesky().unwrap().l(), l, Tolerance::Ulps(4));
+    // f32 inputs carry about 7 significant digits into the same factor
+    assert_matrix_approx_eq!(f32_l, l, Tolerance::Relative(1e-6));
+}
+
+#[test]
+fn test_elementwise_arithmetic() {
+    let a = MatrixBuilder::new().from_mat(vec![vec![1, 2, 3], vec![4, 5, 6]]).build().unwrap();
+    let b = MatrixBuilder::new().from_mat(vec![vec![6, 5, 4], vec![3, 2, 1]]).build().unwrap();
+    let sum = MatrixBuilder::new().from_mat(vec![vec![7, 7, 7], vec![7, 7, 7]]).build().unwrap();
+    let difference = MatrixBuilder::new().from_mat(vec![vec![-5, -3, -1], vec![1, 3, 5]]).build().unwrap();
+    assert_eq!(&a + &b, sum);
+    assert_eq!(a.clone() + b.clone(), sum);
+    assert_eq!(&a - &b, difference);
+    assert_eq!(a.clone() - &b, difference);
+    assert_eq!(-&difference, &b - &a);
+    assert_eq!(&a * 2, a.clone() + &a);
+    assert_eq!((&a * 4) / 2, &a + &a);
+
+    let mut c = a.clone();
+    c += &b;
+    assert_eq!(c, sum);
+    c -= b.clone();
+    assert_eq!(c, a);
+    c *= 3;
+    assert_eq!(c, &a * 3);
+    c /= 3;
+    assert_eq!(c, a);
+
+    let d = MatrixBuilder::new().from_mat(vec![vec![1, 2], vec![3, 4]]).build().unwrap();
+    assert_eq!(a.checked_add(&b), Ok(sum));
+    assert_eq!(a.checked_add(&d), Err(MatrixDimensionMismatchError { left: (2, 3), right: (2, 2) }));
+    assert_eq!(a.checked_sub(&d), Err(MatrixDimensionMismatchError { left: (2, 3), right: (2, 2) }));
+}
+
+#[test]
+#[should_panic]
+fn test_add_dimension_mismatch_panics() {
+    let a = MatrixBuilder::<f64>::new().zeros(2, 3).build().unwrap();
+    let b = MatrixBuilder::<f64>::new().zeros(3, 2).build().unwrap();
+    let _ = a + b;
+}
+
+#[test]
+fn test_matrix_product() {
+    let a = MatrixBuilder::new().from_mat(vec![vec![1f64, 2f64, 3f64], vec![4f64, 5f64, 6f64]]).build().unwrap();
+    let b = MatrixBuilder::new().from_mat(vec![vec![7f64, 8f64], vec![9f64, 10f64], vec![11f64, 12f64]]).build().unwrap();
+    let ab = MatrixBuilder::new().from_mat(vec![vec![58f64, 64f64], vec![139f64, 154f64]]).build().unwrap();
+    let ba = MatrixBuilder::new().from_mat(
+        vec![
+            vec![39f64, 54f64, 69f64],
+            vec![49f64, 68f64, 87f64],
+            vec![59f64, 82f64, 105f64],
+        ]
+    ).build().unwrap();
+    assert_eq!(&a * &b, ab);
+    assert_eq!(&b * &a, ba);
+    assert_eq!(a.clone() * b.clone(), ab);
+    assert_eq!(a.checked_matmul(&b), Ok(ab));
+    assert_eq!(a.checked_matmul(&a), Err(MatrixDimensionMismatchError { left: (2, 3), right: (2, 3) }));
+
+    let identity = MatrixBuilder::<f64>::new().identity(3).build().unwrap();
+    let mut c = a.clone();
+    c *= &identity;
+    assert_eq!(c, a);
+    c *= b;
+    assert_eq!(c.rows(), 2);
+    assert_eq!(c.cols(), 2);
+}
+
+#[test]
+#[should_panic]
+fn test_matrix_product_dimension_mismatch_panics() {
+    let a = MatrixBuilder::<i32>::new().zeros(2, 3).build().unwrap();
+    let _ = &a * &a;
+}
+
+#[test]
+fn test_transpose() {
+    let a = MatrixBuilder::new().from_mat(vec![vec![1, 2, 3], vec![4, 5, 6]]).build().unwrap();
+    let at = MatrixBuilder::new().from_mat(vec![vec![1, 4], vec![2, 5], vec![3, 6]]).build().unwrap();
+    assert_eq!(a.transpose(), at);
+    assert_eq!(a.transpose().transpose(), a);
+
+    let mut b = a.clone();
+    b.transpose_in_place();
+    assert_eq!(b, at);
+    b.transpose_in_place();
+    assert_eq!(b, a);
+
+    let data: Vec<i64> = (0..35).collect();
+    let c = MatrixBuilder::new().from_vec(5, 7, data).build().unwrap();
+    let mut d = c.clone();
+    d.transpose_in_place();
+    assert_eq!(d, c.transpose());
+
+    let mut e = MatrixBuilder::new().from_mat(vec![vec![1f64, 2f64], vec![3f64, 4f64]]).build().unwrap();
+    e.transpose_in_place();
+    assert_eq!(e, MatrixBuilder::new().from_mat(vec![vec![1f64, 3f64], vec![2f64, 4f64]]).build().unwrap());
+}
+
+#[test]
+fn test_row_col() {
+    let a = MatrixBuilder::new().from_mat(vec![vec![1, 2, 3], vec![4, 5, 6]]).build().unwrap();
+    assert_eq!(a.row(0).unwrap(), &[1, 2, 3]);
+    assert_eq!(a.row(1).unwrap(), &[4, 5, 6]);
+    assert!(matches!(a.row(2), Err(MatrixIndexOutOfBoundsError)));
+    assert_eq!(a.col(0).unwrap(), vec![1, 4]);
+    assert_eq!(a.col(2).unwrap(), vec![3, 6]);
+    assert!(matches!(a.col(3), Err(MatrixIndexOutOfBoundsError)));
+}
+
+#[test]
+fn test_submatrix() {
+    let data: Vec<i32> = (0..42).collect();
+    let a = MatrixBuilder::new().from_vec(6, 7, data).build().unwrap();
+    let block = MatrixBuilder::new().from_mat(
+        vec![
+            vec![14, 15, 16],
+            vec![21, 22, 23],
+            vec![28, 29, 30],
+        ]
+    ).build().unwrap();
+    assert_eq!(a.submatrix(2..5, 0..3).unwrap(), block);
+    assert_eq!(a.submatrix(2..=4, ..3).unwrap(), block);
+    assert_eq!(a.submatrix(.., ..).unwrap(), a);
+    assert_eq!(a.submatrix(5.., 6..).unwrap(), MatrixBuilder::new().from_vec(1, 1, vec![41]).build().unwrap());
+    assert!(matches!(a.submatrix(2..7, 0..3), Err(MatrixIndexOutOfBoundsError)));
+    assert!(matches!(a.submatrix(0..3, 0..8), Err(MatrixIndexOutOfBoundsError)));
+    assert!(matches!(a.submatrix(3..3, 0..3), Err(MatrixIndexOutOfBoundsError)));
+}
+
+#[test]
+fn test_matrix_view() {
+    let data: Vec<i32> = (0..20).collect();
+    let a = MatrixBuilder::new().from_vec(4, 5, data.clone()).build().unwrap();
+    let v = a.submatrix_view(1..3, 2..5).unwrap();
+    assert_eq!(v.rows(), 2);
+    assert_eq!(v.cols(), 3);
+    assert_eq!(v.offset(), 7);
+    assert_eq!(v[(0, 0)], 7);
+    assert_eq!(v[(1, 2)], 14);
+    assert_eq!(v.iter().copied().collect::<Vec<i32>>(), vec![7, 8, 9, 12, 13, 14]);
+    assert_eq!(v.to_matrix(), a.submatrix(1..3, 2..5).unwrap());
+
+    let t = v.transpose();
+    assert_eq!(t.rows(), 3);
+    assert_eq!(t.cols(), 2);
+    assert_eq!(t[(2, 1)], 14);
+    assert_eq!(t.to_matrix(), v.to_matrix().transpose());
+    assert_eq!(t.subview(1.., ..1).unwrap().iter().copied().collect::<Vec<i32>>(), vec![8, 9]);
+    assert!(matches!(v.subview(0..3, ..), Err(MatrixIndexOutOfBoundsError)));
+    assert!(matches!(a.submatrix_view(0..5, ..), Err(MatrixIndexOutOfBoundsError)));
+
+    // Every other column of the first two rows
+    let strided = MatrixView::new(&data, 0, 2, 3, 5, 2).unwrap();
+    assert_eq!(strided.iter().copied().collect::<Vec<i32>>(), vec![0, 2, 4, 5, 7, 9]);
+    assert!(MatrixView::new(&data, 1, 2, 3, 5, 2).is_ok());
+    assert!(matches!(MatrixView::new(&data, 1, 4, 3, 5, 2), Err(MatrixIndexOutOfBoundsError)));
+}
+
+#[test]
+fn test_matrix_view_mut() {
+    let mut a = MatrixBuilder::<i32>::new().zeros(3, 4).build().unwrap();
+    {
+        let mut v = a.submatrix_view_mut(1.., 1..3).unwrap();
+        v[(0, 0)] = 1;
+        v[(1, 1)] = 2;
+        let mut t = v.transpose();
+        t[(1, 0)] = 3;
+        assert_eq!(t.as_view()[(1, 0)], 3);
+    }
+    assert_eq!(
+        a,
+        MatrixBuilder::new().from_mat(
+            vec![
+                vec![0, 0, 0, 0],
+                vec![0, 1, 3, 0],
+                vec![0, 0, 2, 0],
+            ]
+        ).build().unwrap()
+    );
+    let mut v = a.view_mut();
+    v.subview_mut(..1, ..).unwrap().fill(7);
+    assert_eq!(a.row(0).unwrap(), &[7, 7, 7, 7]);
+}
+
+#[test]
+fn test_view_symmetric_and_cholesky() {
+    let mut a = MatrixBuilder::new().from_mat(
+        vec![
+            vec![  1f64,   2f64,   4f64,   7f64, -1f64],
+            vec![  2f64,  13f64,  23f64,  38f64, -2f64],
+            vec![  4f64,  23f64,  77f64, 122f64, -3f64],
+            vec![  7f64,  38f64, 122f64, 294f64, -4f64],
+            vec![  5f64,   6f64,   7f64,   8f64, -5f64],
+        ]
+    ).build().unwrap();
+    assert!(!a.is_symmetric());
+    assert!(!a.is_positive_definite());
+    let block = a.submatrix_view(..4, ..4).unwrap();
+    assert!(block.is_square());
+    assert!(block.is_symmetric());
+    assert!(block.is_positive_definite());
+    let l = MatrixBuilder::new().from_mat(
+        vec![
+            vec![1f64, 0f64, 0f64, 0f64], 
+            vec![2f64, 3f64, 0f64, 0f64], 
+            vec![4f64, 5f64, 6f64, 0f64], 
+            vec![7f64, 8f64, 9f64, 10f64]
+        ]
+    ).build().unwrap();
+    assert_eq!(block.cholesky().unwrap().l(), &l);
+
+    a.submatrix_view_mut(..4, ..4).unwrap().cholesky_in_place().unwrap();
+    assert_eq!(a.submatrix(..4, ..4).unwrap(), l);
+    assert_eq!(a.col(4).unwrap(), vec![-1f64, -2f64, -3f64, -4f64, -5f64]);
+    assert_eq!(a.row(4).unwrap(), &[5f64, 6f64, 7f64, 8f64, -5f64]);
+
+    let mut b = MatrixBuilder::new().from_mat(vec![vec![0f64, 1f64], vec![1f64, 3f64]]).build().unwrap();
+    assert_eq!(b.view_mut().cholesky_in_place(), Err(CholeskyDecompositionError::LeadingMinorIsNotPositive { index: 0, pivot: 0f64 }));
+    let mut c = MatrixBuilder::new().from_mat(vec![vec![1f64, 1f64], vec![0f64, 3f64]]).build().unwrap();
+    assert_eq!(c.view_mut().cholesky_in_place(), Err(CholeskyDecompositionError::MatrixIsNotPositiveDefinite));
+}
+
+#[test]
+fn test_lu() {
+    let a = MatrixBuilder::new().from_mat(
+        vec![
+            vec![ 2i32,  1i32, 1i32],
+            vec![ 4i32, -6i32, 0i32],
+            vec![-2i32,  7i32, 2i32],
+        ]
+    ).build().unwrap();
+    let lu = a.lu().unwrap();
+    assert!(!lu.is_singular());
+    assert_eq!(lu.permutation(), &[1, 0, 2]);
+    let l = lu.l();
+    let u = lu.u();
+    for i in 0..3 {
+        assert_eq!(l[(i, i)], 1f64);
+        for j in (i + 1)..3 {
+            assert_eq!(l[(i, j)], 0f64);
+            assert_eq!(u[(j, i)], 0f64);
+        }
+    }
+    let a = a.copy_to::<f64>().unwrap();
+    assert_matrix_approx_eq!(&lu.p() * &a, &l * &u, Tolerance::Absolute(1e-12));
+    assert!((lu.determinant() + 16f64).abs() < 1e-12);
+
+    let identity = MatrixBuilder::<f64>::new().identity(3).build().unwrap();
+    let inverse = lu.inverse().unwrap();
+    assert_matrix_approx_eq!(&inverse * &a, identity, Tolerance::Absolute(1e-12));
+    assert_matrix_approx_eq!(&a * &inverse, identity, Tolerance::Absolute(1e-12));
+
+    let b = MatrixBuilder::new().from_mat(vec![vec![5f64, 1f64], vec![-2f64, 0f64], vec![9f64, 2f64]]).build().unwrap();
+    let x = lu.solve(&b).unwrap();
+    assert_matrix_approx_eq!(&a * &x, b, Tolerance::Absolute(1e-12));
+    assert_matrix_approx_eq!(x.submatrix(.., ..1).unwrap(), MatrixBuilder::new().from_vec(3, 1, vec![1f64, 1f64, 2f64]).build().unwrap(), Tolerance::Absolute(1e-12));
+
+    let c = MatrixBuilder::new().from_vec(2, 1, vec![1f64, 2f64]).build().unwrap();
+    assert_eq!(lu.solve(&c), Err(LuDecompositionError::DimensionMismatch));
+}
+
+#[test]
+fn test_lu_singular() {
+    let a = MatrixBuilder::new().from_mat(
+        vec![
+            vec![1f64, 2f64, 3f64],
+            vec![4f64, 5f64, 6f64],
+            vec![7f64, 8f64, 9f64],
+        ]
+    ).build().unwrap();
+    let lu = a.lu().unwrap();
+    assert!(lu.is_singular());
+    assert!(lu.determinant().abs() < 1e-12);
+    assert_eq!(lu.inverse(), Err(LuDecompositionError::MatrixIsSingular));
+
+    let a = MatrixBuilder::new().from_mat(vec![vec![0, 0], vec![0, 1]]).build().unwrap();
+    let lu = a.lu().unwrap();
+    assert!(lu.is_singular());
+    assert_eq!(lu.determinant(), 0f64);
+    assert_eq!(lu.solve(&a), Err(LuDecompositionError::MatrixIsSingular));
+
+    let a = MatrixBuilder::<i32>::new().zeros(2, 3).build().unwrap();
+    assert!(matches!(a.lu(), Err(LuDecompositionError::MatrixIsNotSquare)));
+}
+
+#[test]
+fn test_triangular_solve() {
+    let l = MatrixBuilder::new().from_mat(
+        vec![
+            vec![2i32, 0i32, 0i32],
+            vec![1i32, 3i32, 0i32],
+            vec![4i32, 5i32, 6i32],
+        ]
+    ).build().unwrap();
+    let u = l.transpose();
+    assert!(l.is_lower_triangular());
+    assert!(!l.is_upper_triangular());
+    assert!(u.is_upper_triangular());
+    assert!(!u.is_lower_triangular());
+    assert!(MatrixBuilder::<i32>::new().identity(3).build().unwrap().is_lower_triangular());
+
+    let b = MatrixBuilder::new().from_mat(vec![vec![2f64, 4f64], vec![7f64, 5f64], vec![32f64, 19f64]]).build().unwrap();
+    let x = l.forward_substitution(&b).unwrap();
+    assert_matrix_approx_eq!(x, MatrixBuilder::new().from_mat(vec![vec![1f64, 2f64], vec![2f64, 1f64], vec![3f64, 1f64]]).build().unwrap(), Tolerance::Absolute(1e-12));
+    let y = u.back_substitution(&b).unwrap();
+    assert_matrix_approx_eq!(&u.copy_to::<f64>().unwrap() * &y, b, Tolerance::Absolute(1e-12));
+
+    let c = MatrixBuilder::new().from_vec(2, 1, vec![1f64, 2f64]).build().unwrap();
+    assert_eq!(l.forward_substitution(&c), Err(TriangularSolveError::DimensionMismatch));
+    let singular = MatrixBuilder::new().from_mat(vec![vec![1f64, 0f64], vec![1f64, 0f64]]).build().unwrap();
+    assert_eq!(singular.forward_substitution(&c), Err(TriangularSolveError::MatrixIsSingular));
+    let rectangular = MatrixBuilder::<f64>::new().zeros(2, 3).build().unwrap();
+    assert_eq!(rectangular.back_substitution(&c), Err(TriangularSolveError::MatrixIsNotSquare));
+}
+
+#[test]
+fn test_cholesky_factor() {
+    let a = MatrixBuilder::new().from_mat(
+        vec![
+            vec![  1i32,   2i32,   4i32,   7i32],
+            vec![  2i32,  13i32,  23i32,  38i32],
+            vec![  4i32,  23i32,  77i32, 122i32],
+            vec![  7i32,  38i32, 122i32, 294i32]
+        ]
+    ).build().unwrap();
+    let factor = a.cholesky().unwrap();
+    let a = a.copy_to::<f64>().unwrap();
+    assert_eq!(factor.dim(), 4);
+    assert!(factor.l().is_lower_triangular());
+    assert_matrix_approx_eq!(factor.l() * &factor.l().transpose(), a, Tolerance::Absolute(1e-12));
+    assert!((factor.determinant() - 32400f64).abs() < 1e-8);
+    assert!((factor.log_determinant() - 32400f64.ln()).abs() < 1e-12);
+
+    let identity = MatrixBuilder::<f64>::new().identity(4).build().unwrap();
+    assert_matrix_approx_eq!(&a * &factor.inverse(), identity, Tolerance::Absolute(1e-10));
+
+    let b = MatrixBuilder::new().from_mat(
+        vec![
+            vec![1f64, 0f64],
+            vec![2f64, 1f64],
+            vec![3f64, 0f64],
+            vec![4f64, 1f64],
+        ]
+    ).build().unwrap();
+    let x = factor.solve(&b).unwrap();
+    assert_matrix_approx_eq!(&a * &x, b, Tolerance::Absolute(1e-10));
+    let mut y = b.clone();
+    factor.solve_in_place(&mut y).unwrap();
+    assert_eq!(x, y);
+
+    let v = factor.solve_vector(&[1i32, 2i32, 3i32, 4i32]).unwrap();
+    assert_eq!(v, x.col(0).unwrap());
+
+    assert_eq!(factor.solve_vector(&[1f64, 2f64]), Err(CholeskyDecompositionError::DimensionMismatch));
+    assert_eq!(factor.solve(&b.transpose()), Err(CholeskyDecompositionError::DimensionMismatch));
+}
+
+#[test]
+fn test_qr() {
+    let a = MatrixBuilder::new().from_mat(
+        vec![
+            vec![12i32, -51i32,   4i32],
+            vec![ 6i32, 167i32, -68i32],
+            vec![-4i32,  24i32, -41i32],
+            vec![-1i32,   1i32,   0i32],
+        ]
+    ).build().unwrap();
+    let qr = a.qr();
+    let a = a.copy_to::<f64>().unwrap();
+    assert_eq!(qr.rank(), 3);
+    assert_eq!(qr.permutation(), &[0, 1, 2]);
+
+    let q = qr.q();
+    let r = qr.r();
+    assert_eq!((q.rows(), q.cols()), (4, 4));
+    assert_eq!((r.rows(), r.cols()), (4, 3));
+    assert!(r.submatrix(..3, ..).unwrap().is_upper_triangular());
+    assert_eq!(r.row(3).unwrap(), &[0f64, 0f64, 0f64]);
+    assert_matrix_approx_eq!(&q * &r, a, Tolerance::Absolute(1e-10));
+    assert_matrix_approx_eq!(&q.transpose() * &q, MatrixBuilder::<f64>::new().identity(4).build().unwrap(), Tolerance::Absolute(1e-12));
+
+    let thin_q = qr.thin_q();
+    let thin_r = qr.thin_r();
+    assert_eq!((thin_q.rows(), thin_q.cols()), (4, 3));
+    assert_eq!((thin_r.rows(), thin_r.cols()), (3, 3));
+    assert_matrix_approx_eq!(&thin_q * &thin_r, a, Tolerance::Absolute(1e-10));
+    assert_matrix_approx_eq!(&thin_q.transpose() * &thin_q, MatrixBuilder::<f64>::new().identity(3).build().unwrap(), Tolerance::Absolute(1e-12));
+
+    let wide = a.transpose();
+    let qr = wide.qr();
+    assert_eq!((qr.thin_q().rows(), qr.thin_q().cols()), (3, 3));
+    assert_eq!((qr.thin_r().rows(), qr.thin_r().cols()), (3, 4));
+    assert_matrix_approx_eq!(&qr.q() * &qr.r(), wide, Tolerance::Absolute(1e-10));
+}
+
+#[test]
+fn test_qr_pivoted() {
+    // Third column is the sum of the first two
+    let a = MatrixBuilder::new().from_mat(
+        vec![
+            vec![1f64, 2f64,  3f64],
+            vec![4f64, 5f64,  9f64],
+            vec![7f64, 8f64, 15f64],
+            vec![1f64, 0f64,  1f64],
+        ]
+    ).build().unwrap();
+    let qr = a.qr_pivoted();
+    assert_eq!(qr.rank(), 2);
+    assert_eq!(qr.permutation()[0], 2);
+    let mut ap = a.clone();
+    for j in 0..3 {
+        for i in 0..4 {
+            ap[(i, j)] = a[(i, qr.permutation()[j])];
+        }
+    }
+    assert_matrix_approx_eq!(&qr.q() * &qr.r(), ap, Tolerance::Absolute(1e-10));
+    let r = qr.r();
+    assert!(r[(0, 0)].abs() >= r[(1, 1)].abs());
+    assert!(r[(2, 2)].abs() < 1e-12);
+
+    let b = MatrixBuilder::new().from_vec(4, 1, vec![1f64, 2f64, 3f64, 4f64]).build().unwrap();
+    assert!(matches!(a.qr().solve_least_squares(&b), Err(QrDecompositionError::MatrixIsRankDeficient)));
+    let x = qr.solve_least_squares(&b).unwrap();
+    assert_eq!(x[(qr.permutation()[2], 0)], 0f64);
+    let q1 = qr.thin_q().submatrix(.., ..2).unwrap();
+    assert_matrix_approx_eq!(&a * &x, &(&q1 * &q1.transpose()) * &b, Tolerance::Absolute(1e-10));
+}
+
+#[test]
+fn test_least_squares() {
+    // y = 1 + 2x exactly
+    let a = MatrixBuilder::new().from_mat(
+        vec![
+            vec![1i32, 0i32],
+            vec![1i32, 1i32],
+            vec![1i32, 2i32],
+            vec![1i32, 3i32],
+        ]
+    ).build().unwrap();
+    let b = MatrixBuilder::new().from_vec(4, 1, vec![1i32, 3i32, 5i32, 7i32]).build().unwrap();
+    let x = least_squares(&a, &b).unwrap();
+    assert_matrix_approx_eq!(x, MatrixBuilder::new().from_vec(2, 1, vec![1f64, 2f64]).build().unwrap(), Tolerance::Absolute(1e-12));
+
+    // Noisy observations agree with the normal equations
+    let b = MatrixBuilder::new().from_mat(vec![vec![1.1f64, 0f64], vec![2.9f64, 1f64], vec![5.2f64, 0f64], vec![6.8f64, 1f64]]).build().unwrap();
+    let x = least_squares(&a, &b).unwrap();
+    let a = a.copy_to::<f64>().unwrap();
+    let at = a.transpose();
+    let normal = (&at * &a).cholesky().unwrap().solve(&(&at * &b)).unwrap();
+    assert_matrix_approx_eq!(x, normal, Tolerance::Absolute(1e-12));
+    assert_matrix_approx_eq!(x, a.qr().solve_least_squares(&b).unwrap(), Tolerance::Absolute(1e-12));
+
+    let c = MatrixBuilder::new().from_vec(3, 1, vec![1f64, 2f64, 3f64]).build().unwrap();
+    assert_eq!(least_squares(&a, &c), Err(QrDecompositionError::DimensionMismatch));
+
+    // Columns already in norm order, pivoting leaves the permutation unchanged
+    let a = MatrixBuilder::new().from_mat(vec![vec![1f64, 0f64], vec![0f64, 0f64]]).build().unwrap();
+    let b = MatrixBuilder::new().from_vec(2, 1, vec![1f64, 1f64]).build().unwrap();
+    assert_eq!(least_squares(&a, &b).unwrap().data(), &vec![1f64, 0f64]);
+    assert_eq!(a.qr().solve_least_squares(&b), Err(QrDecompositionError::MatrixIsRankDeficient));
+}
+
+#[test]
+fn test_svd() {
+    let a = MatrixBuilder::new().from_mat(
+        vec![
+            vec![3i32, 2i32,  2i32],
+            vec![2i32, 3i32, -2i32],
+        ]
+    ).build().unwrap();
+    let svd = a.svd().unwrap();
+    let a = a.copy_to::<f64>().unwrap();
+    assert_eq!(svd.singular_values().len(), 2);
+    assert!((svd.singular_values()[0] - 5f64).abs() < 1e-12);
+    assert!((svd.singular_values()[1] - 3f64).abs() < 1e-12);
+    assert_eq!((svd.thin_u().rows(), svd.thin_u().cols()), (2, 2));
+    assert_eq!((svd.thin_v().rows(), svd.thin_v().cols()), (3, 2));
+    assert_matrix_approx_eq!(&(svd.thin_u() * &svd.sigma()) * &svd.thin_v().transpose(), a, Tolerance::Absolute(1e-12));
+
+    let v = svd.v();
+    assert_eq!((v.rows(), v.cols()), (3, 3));
+    assert_matrix_approx_eq!(&v.transpose() * &v, MatrixBuilder::<f64>::new().identity(3).build().unwrap(), Tolerance::Absolute(1e-12));
+    assert_matrix_approx_eq!(v.submatrix(.., ..2).unwrap(), svd.thin_v(), Tolerance::Absolute(0f64));
+
+    let tall = a.transpose();
+    let svd = tall.svd().unwrap();
+    assert_eq!((svd.thin_u().rows(), svd.thin_u().cols()), (3, 2));
+    let u = svd.u();
+    assert_matrix_approx_eq!(&u.transpose() * &u, MatrixBuilder::<f64>::new().identity(3).build().unwrap(), Tolerance::Absolute(1e-12));
+    assert_matrix_approx_eq!(&(svd.thin_u() * &svd.sigma()) * &svd.thin_v().transpose(), tall, Tolerance::Absolute(1e-12));
+    assert_eq!(svd.numerical_rank(), 2);
+    assert!((svd.condition_number() - 5f64 / 3f64).abs() < 1e-12);
+}
+
+#[test]
+fn test_svd_rank_deficient() {
+    let a = MatrixBuilder::new().from_mat(
+        vec![
+            vec![1f64, 2f64,  3f64],
+            vec![4f64, 5f64,  9f64],
+            vec![7f64, 8f64, 15f64],
+            vec![1f64, 0f64,  1f64],
+        ]
+    ).build().unwrap();
+    let svd = a.svd().unwrap();
+    let s = svd.singular_values();
+    assert!(s[0] >= s[1] && s[1] >= s[2]);
+    assert_eq!(svd.numerical_rank(), 2);
+    assert_eq!(svd.rank(s[1] * 2f64), 1);
+    assert!(svd.condition_number() > 1e12);
+    let u = svd.thin_u();
+    assert_matrix_approx_eq!(&u.transpose() * u, MatrixBuilder::<f64>::new().identity(3).build().unwrap(), Tolerance::Absolute(1e-12));
+    assert_matrix_approx_eq!(svd.low_rank_approximation(2).unwrap(), a, Tolerance::Absolute(1e-12));
+
+    // Moore-Penrose conditions
+    let pinv = svd.pseudoinverse();
+    assert_eq!((pinv.rows(), pinv.cols()), (3, 4));
+    assert_matrix_approx_eq!(&(&a * &pinv) * &a, a, Tolerance::Absolute(1e-10));
+    assert_matrix_approx_eq!(&(&pinv * &a) * &pinv, pinv, Tolerance::Absolute(1e-10));
+    let b = MatrixBuilder::new().from_vec(4, 1, vec![1f64, 2f64, 3f64, 4f64]).build().unwrap();
+    let x = &pinv * &b;
+    let q1 = a.qr_pivoted().thin_q().submatrix(.., ..2).unwrap();
+    assert_matrix_approx_eq!(&a * &x, &(&q1 * &q1.transpose()) * &b, Tolerance::Absolute(1e-10));
+
+    // Best rank one approximation error is the second singular value in 2-norm
+    let residual = &a - &svd.low_rank_approximation(1).unwrap();
+    let residual_svd = residual.svd().unwrap();
+    assert!((residual_svd.singular_values()[0] - s[1]).abs() < 1e-10);
+    assert!(svd.low_rank_approximation(0).is_err());
+    assert!(svd.low_rank_approximation(4).is_err());
+
+    let zero = MatrixBuilder::<f64>::new().zeros(2, 2).build().unwrap();
+    let svd = zero.svd().unwrap();
+    assert_eq!(svd.numerical_rank(), 0);
+    assert_eq!(svd.condition_number(), f64::INFINITY);
+    assert_eq!(svd.pseudoinverse(), zero);
+}
+
+#[test]
+fn test_tridiagonal_ql_agrees_with_jacobi() {
+    let matrices = vec![
+        MatrixBuilder::new().from_mat(
+            vec![
+                vec![1f64, 4f64, 5f64],
+                vec![4f64, 2f64, 6f64],
+                vec![5f64, 6f64, 3f64],
+            ]
+        ).build().unwrap(),
+        MatrixBuilder::new().from_mat(
+            vec![
+                vec![1f64, 4f64, 5f64, 7f64],
+                vec![4f64, 2f64, 6f64, 8f64],
+                vec![5f64, 6f64, 3f64, 9f64],
+                vec![7f64, 8f64, 9f64, 10f64],
+            ]
+        ).build().unwrap(),
+        MatrixBuilder::new().from_mat(
+            vec![
+                vec![  1f64,   2f64,   4f64,   7f64],
+                vec![  2f64,  13f64,  23f64,  38f64],
+                vec![  4f64,  23f64,  77f64, 122f64],
+                vec![  7f64,  38f64, 122f64, 294f64]
+            ]
+        ).build().unwrap(),
+        MatrixBuilder::new().from_mat(vec![vec![4f64, 1f64], vec![1f64, 3f64]]).build().unwrap(),
+        MatrixBuilder::new().from_mat(vec![vec![2f64]]).build().unwrap(),
+    ];
+    for m in matrices {
+        let (d_jacobi, v_jacobi) = m.eigen_decomposition(Algorithm::Jacobi).unwrap();
+        let (d, v) = m.eigen_decomposition(Algorithm::TridiagonalQL).unwrap();
+        for (a, b) in d.iter().zip(d_jacobi.iter()) {
+            assert!((a - b).abs() < 1e-10 * b.abs().max(1f64), "{:?} {:?}", d, d_jacobi);
+        }
+        for j in 0..v.cols() {
+            let dot: f64 = (0..v.rows()).map(|i| v[(i, j)] * v_jacobi[(i, j)]).sum();
+            assert!((dot.abs() - 1f64).abs() < 1e-10);
+        }
+    }
+
+    let m = MatrixBuilder::new().from_mat(vec![vec![1i32, 4i32, 5i32], vec![4i32, 2i32, 6i32], vec![5i32, 6i32, 3i32]]).build().unwrap();
+    let (d, _) = m.eigen_decomposition(Algorithm::TridiagonalQL).unwrap();
+    assert!((d[0] - 12.175971065046905f64).abs() < 1e-12);
+    let m = MatrixBuilder::new().from_mat(vec![vec![0, 1], vec![2, 3]]).build().unwrap();
+    assert!(m.eigen_decomposition(Algorithm::TridiagonalQL).is_err());
+}
+
+#[test]
+fn test_tridiagonal_ql_large() {
+    let n = 300;
+    let x = pseudo_random_matrix(n, n, 42);
+    let a = &x + &x.transpose();
+    let (d, v) = a.eigen_decomposition(Algorithm::TridiagonalQL).unwrap();
+    assert_eq!(d.len(), n);
+    assert!(d.windows(2).all(|w| w[0] >= w[1]));
+    let av = &a * &v;
+    for j in 0..n {
+        for i in 0..n {
+            assert!((av[(i, j)] - d[j] * v[(i, j)]).abs() < 1e-10);
+        }
+    }
+    assert_matrix_approx_eq!(&v.transpose() * &v, MatrixBuilder::<f64>::new().identity(n).build().unwrap(), Tolerance::Absolute(1e-10));
+    let trace: f64 = (0..n).map(|k| a[(k, k)]).sum();
+    assert!((d.iter().sum::<f64>() - trace).abs() < 1e-9);
+}
+
+#[test]
+fn test_general_eigen_values() {
+    let a = MatrixBuilder::new().from_mat(vec![vec![0i32, -1i32], vec![1i32, 0i32]]).build().unwrap();
+    let mut eigen_values = a.general_eigen_values().unwrap();
+    eigen_values.sort_by(|x, y| x.im.partial_cmp(&y.im).unwrap());
+    assert!((eigen_values[0].re).abs() < 1e-12 && (eigen_values[0].im + 1f64).abs() < 1e-12);
+    assert!((eigen_values[1].re).abs() < 1e-12 && (eigen_values[1].im - 1f64).abs() < 1e-12);
+    assert_eq!(eigen_values[0], eigen_values[1].conj());
+
+    let a = MatrixBuilder::new().from_mat(
+        vec![
+            vec![ 1f64, 2f64, 0f64],
+            vec![-2f64, 1f64, 0f64],
+            vec![ 0f64, 0f64, 3f64],
+        ]
+    ).build().unwrap();
+    let decomposition = a.general_eigen_decomposition().unwrap();
+    let eigen_values = decomposition.eigen_values();
+    assert_eq!(eigen_values.iter().filter(|x| x.is_real()).count(), 1);
+    for (j, &lambda) in eigen_values.iter().enumerate() {
+        if lambda.is_real() {
+            assert!((lambda.re - 3f64).abs() < 1e-12);
+        } else {
+            assert!((lambda.re - 1f64).abs() < 1e-12 && (lambda.im.abs() - 2f64).abs() < 1e-12);
+        }
+        assert_eigen_pair(&a, lambda, &decomposition.eigen_vector(j), 1e-10);
+    }
+
+    // Row stochastic transition matrix has 1 as its dominant eigen value
+    let p = MatrixBuilder::new().from_mat(
+        vec![
+            vec![0.5f64, 0.3f64, 0.2f64],
+            vec![0.1f64, 0.8f64, 0.1f64],
+            vec![0.4f64, 0.1f64, 0.5f64],
+        ]
+    ).build().unwrap();
+    let eigen_values = p.general_eigen_values().unwrap();
+    assert!(eigen_values.iter().any(|x| (x.re - 1f64).abs() < 1e-12 && x.im.abs() < 1e-12));
+    assert!(eigen_values.iter().all(|x| x.norm() <= 1f64 + 1e-12));
+
+    let rectangular = MatrixBuilder::<f64>::new().zeros(2, 3).build().unwrap();
+    assert!(rectangular.general_eigen_values().is_err());
+}
+
+#[test]
+fn test_general_eigen_decomposition_random() {
+    let n = 20;
+    let a = pseudo_random_matrix(n, n, 7);
+    let decomposition = a.general_eigen_decomposition().unwrap();
+    let eigen_values = decomposition.eigen_values();
+    assert_eq!(eigen_values.len(), n);
+    assert!(eigen_values.iter().any(|x| !x.is_real()));
+    for (j, &lambda) in eigen_values.iter().enumerate() {
+        assert_eigen_pair(&a, lambda, &decomposition.eigen_vector(j), 1e-9);
+    }
+    let trace: f64 = (0..n).map(|k| a[(k, k)]).sum();
+    assert!((eigen_values.iter().map(|x| x.re).sum::<f64>() - trace).abs() < 1e-10);
+    assert!(eigen_values.iter().map(|x| x.im).sum::<f64>().abs() < 1e-10);
+}
+
+#[test]
+fn test_real_schur_form() {
+    let n = 12;
+    let a = pseudo_random_matrix(n, n, 11);
+    let schur = a.schur().unwrap();
+    let q = schur.q();
+    let t = schur.t();
+    assert_matrix_approx_eq!(&q.transpose() * q, MatrixBuilder::<f64>::new().identity(n).build().unwrap(), Tolerance::Absolute(1e-12));
+    assert_matrix_approx_eq!(&(q * t) * &q.transpose(), a, Tolerance::Absolute(1e-12));
+    for i in 1..n {
+        for j in 0..(i - 1) { assert_eq!(t[(i, j)], 0f64); }
+    }
+    for i in 1..(n - 1) {
+        assert!(t[(i, i - 1)] == 0f64 || t[(i + 1, i)] == 0f64);
+    }
+    for (k, lambda) in schur.eigen_values().iter().enumerate() {
+        if lambda.is_real() {
+            assert!((t[(k, k)] - lambda.re).abs() < 1e-12);
+        }
+    }
+}
+
+#[test]
+fn test_tolerance() {
+    assert!(Tolerance::Absolute(1e-3).is_within(1f64, 1.0005f64));
+    assert!(!Tolerance::Absolute(1e-3).is_within(1f64, 1.002f64));
+    assert!(Tolerance::Relative(1e-3).is_within(1000f64, 1000.5f64));
+    assert!(!Tolerance::Relative(1e-3).is_within(1f64, 1.002f64));
+    assert!(Tolerance::Relative(0f64).is_within(0f64, 0f64));
+}
+
+#[test]
+fn test_symmetric_within_tolerance() {
+    // (A·B)·Aᵀ with B symmetric is mathematically symmetric but rounding in f32 breaks exact symmetry
+    let a = pseudo_random_matrix(6, 6, 3).copy_to::<f32>().unwrap();
+    let b = &a.transpose() * &a;
+    let c = &(&a * &b) * &a.transpose();
+    assert!(!c.is_symmetric());
+    assert!(c.is_symmetric_within(Tolerance::Relative(1e-5)));
+    assert!(c.is_symmetric_within(Tolerance::Absolute(1e-5)));
+    assert!(!c.is_symmetric_within(Tolerance::Absolute(0f64)));
+
+    let symmetrized = c.symmetrize().unwrap();
+    assert!(symmetrized.is_symmetric());
+    assert!(symmetrized.view().is_symmetric_within(Tolerance::Absolute(0f64)));
+
+    let d = MatrixBuilder::new().from_mat(vec![vec![1f64, 2f64], vec![3f64, 4f64]]).build().unwrap();
+    assert!(!d.is_symmetric_within(Tolerance::Relative(0.1f64)));
+    assert!(d.is_symmetric_within(Tolerance::Relative(0.5f64)));
+    assert_eq!(d.symmetrize().unwrap(), MatrixBuilder::new().from_mat(vec![vec![1f64, 2.5f64], vec![2.5f64, 4f64]]).build().unwrap());
+    let rectangular = MatrixBuilder::<f64>::new().zeros(2, 3).build().unwrap();
+    assert!(!rectangular.is_symmetric_within(Tolerance::Absolute(1f64)));
+    assert_eq!(rectangular.symmetrize(), Err(MatrixDimensionMismatchError { left: (2, 3), right: (3, 2) }));
+}
+
+#[test]
+fn test_positive_definite_within_tolerance() {
+    let a = pseudo_random_matrix(5, 5, 5).copy_to::<f32>().unwrap();
+    let b = &a.transpose() * &a;
+    let c = &(&(&a * &b) * &a.transpose()) + &MatrixBuilder::<f32>::new().identity(5).build().unwrap();
+    assert!(!c.is_symmetric());
+    assert!(!c.is_positive_definite());
+    assert_eq!(c.cholesky(), Err(CholeskyDecompositionError::MatrixIsNotPositiveDefinite));
+    assert!(c.is_positive_definite_within(Tolerance::Relative(1e-5)));
+    assert!(!c.is_positive_definite_within(Tolerance::Absolute(0f64)));
+
+    let factor = c.cholesky_within(Tolerance::Relative(1e-5)).unwrap();
+    let c = c.copy_to::<f64>().unwrap();
+    assert_matrix_approx_eq!(factor.l() * &factor.l().transpose(), c, Tolerance::Absolute(1e-5));
+    assert_eq!(&factor, &c.symmetrize().unwrap().cholesky().unwrap());
+
+    let indefinite = MatrixBuilder::new().from_mat(vec![vec![1f64, 2f64], vec![2.0000001f64, 1f64]]).build().unwrap();
+    assert!(!indefinite.is_positive_definite_within(Tolerance::Relative(1e-6)));
+    assert!(matches!(
+        indefinite.cholesky_within(Tolerance::Relative(1e-6)), 
+        Err(CholeskyDecompositionError::LeadingMinorIsNotPositive { index: 1, .. })
+    ));
+}
+
+#[test]
+fn test_cholesky_reports_failed_leading_minor() {
+    // Leading minors are 1, 1 and -12, so the third pivot is -12 / 1
+    let a = MatrixBuilder::new().from_mat(
+        vec![
+            vec![1i32, 2i32, 3i32],
+            vec![2i32, 5i32, 4i32],
+            vec![3i32, 4i32, 1i32],
+        ]
+    ).build().unwrap();
+    assert!(!a.is_positive_definite());
+    assert_eq!(a.cholesky(), Err(CholeskyDecompositionError::LeadingMinorIsNotPositive { index: 2, pivot: -12f64 }));
+    assert_eq!(
+        format!("{}", a.cholesky().unwrap_err()),
+        "Cholesky Decomposition failed because leading minor 3 has non positive pivot -12."
+    );
+
+    let c = MatrixBuilder::new().from_mat(vec![vec![4i32, 1i32], vec![2i32, 3i32]]).build().unwrap();
+    assert_eq!(c.cholesky(), Err(CholeskyDecompositionError::MatrixIsNotPositiveDefinite));
+}
+
+fn permuted(a: &Matrix<f64>, permutation: &[usize]) -> Matrix<f64> {
+    let mut pap = a.clone();
+    for (i, &p) in permutation.iter().enumerate() {
+        for (j, &q) in permutation.iter().enumerate() {
+            pap[(i, j)] = a[(p, q)];
+        }
+    }
+    pap
+}
+
+#[test]
+fn test_ldlt() {
+    let a = MatrixBuilder::new().from_mat(
+        vec![
+            vec![ 1i32,  4i32,  5i32,  7i32],
+            vec![ 4i32,  2i32,  6i32,  8i32],
+            vec![ 5i32,  6i32,  3i32,  9i32],
+            vec![ 7i32,  8i32,  9i32, 10i32],
+        ]
+    ).build().unwrap();
+    let ldlt = a.ldlt().unwrap();
+    let a = a.copy_to::<f64>().unwrap();
+    let l = ldlt.l();
+    assert!(l.is_lower_triangular());
+    assert!((0..4).all(|k| l[(k, k)] == 1f64));
+    assert_matrix_approx_eq!(&(l * ldlt.d()) * &l.transpose(), permuted(&a, ldlt.permutation()), Tolerance::Absolute(1e-12));
+    assert_eq!(ldlt.block_sizes().iter().sum::<usize>(), 4);
+
+    let (eigen_values, _) = a.eigen_decomposition(Algorithm::Jacobi).unwrap();
+    let positive = eigen_values.iter().filter(|&&x| x > 0f64).count();
+    assert_eq!(ldlt.inertia(), Inertia { positive, negative: 4 - positive, zero: 0 });
+    assert!((ldlt.determinant() - a.lu().unwrap().determinant()).abs() < 1e-9);
+
+    let b = MatrixBuilder::new().from_mat(vec![vec![1f64, 0f64], vec![2f64, 1f64], vec![3f64, 0f64], vec![4f64, 1f64]]).build().unwrap();
+    let x = ldlt.solve(&b).unwrap();
+    assert_matrix_approx_eq!(&a * &x, b, Tolerance::Absolute(1e-12));
+    assert_eq!(ldlt.solve(&b.transpose()).unwrap_err(), LdltDecompositionError::DimensionMismatch);
+
+    let not_symmetric = MatrixBuilder::new().from_mat(vec![vec![1f64, 2f64], vec![3f64, 4f64]]).build().unwrap();
+    assert!(matches!(not_symmetric.ldlt(), Err(LdltDecompositionError::MatrixIsNotSymmetric)));
+}
+
+#[test]
+fn test_ldlt_two_by_two_pivot() {
+    let a = MatrixBuilder::new().from_mat(
+        vec![
+            vec![0f64, 1f64, 2f64],
+            vec![1f64, 0f64, 3f64],
+            vec![2f64, 3f64, 0f64],
+        ]
+    ).build().unwrap();
+    let ldlt = a.ldlt().unwrap();
+    assert!(ldlt.block_sizes().contains(&2));
+    assert_matrix_approx_eq!(&(ldlt.l() * ldlt.d()) * &ldlt.l().transpose(), permuted(&a, ldlt.permutation()), Tolerance::Absolute(1e-12));
+    assert_eq!(ldlt.inertia(), Inertia { positive: 1, negative: 2, zero: 0 });
+    let b = MatrixBuilder::new().from_vec(3, 1, vec![1f64, 2f64, 3f64]).build().unwrap();
+    assert_matrix_approx_eq!(&a * &ldlt.solve(&b).unwrap(), b, Tolerance::Absolute(1e-12));
+
+    let n = 40;
+    let x = pseudo_random_matrix(n, n, 13);
+    let a = &x + &x.transpose();
+    let ldlt = a.ldlt().unwrap();
+    assert_matrix_approx_eq!(&(ldlt.l() * ldlt.d()) * &ldlt.l().transpose(), permuted(&a, ldlt.permutation()), Tolerance::Absolute(1e-10));
+    assert!(ldlt.l().data().iter().all(|x| x.abs() <= 1f64 / (1f64 - (1f64 + 17f64.sqrt()) / 8f64) + 1e-12));
+    let (eigen_values, _) = a.eigen_decomposition(Algorithm::TridiagonalQL).unwrap();
+    let positive = eigen_values.iter().filter(|&&x| x > 0f64).count();
+    assert_eq!(ldlt.inertia(), Inertia { positive, negative: n - positive, zero: 0 });
+    let b = pseudo_random_matrix(n, 2, 17);
+    assert_matrix_approx_eq!(&a * &ldlt.solve(&b).unwrap(), b, Tolerance::Absolute(1e-9));
+}
+
+#[test]
+fn test_ldlt_singular() {
+    let a = MatrixBuilder::new().from_mat(
+        vec![
+            vec![1f64, 2f64, 3f64],
+            vec![2f64, 4f64, 6f64],
+            vec![3f64, 6f64, -1f64],
+        ]
+    ).build().unwrap();
+    let ldlt = a.ldlt().unwrap();
+    assert_eq!(ldlt.inertia(), Inertia { positive: 1, negative: 1, zero: 1 });
+    assert!(ldlt.determinant().abs() < 1e-12);
+    let b = MatrixBuilder::new().from_vec(3, 1, vec![1f64, 2f64, 3f64]).build().unwrap();
+    assert_eq!(ldlt.solve(&b).unwrap_err(), LdltDecompositionError::MatrixIsSingular);
+
+    let zero = MatrixBuilder::<f64>::new().zeros(2, 2).build().unwrap();
+    assert_eq!(zero.ldlt().unwrap().inertia(), Inertia { positive: 0, negative: 0, zero: 2 });
+}
+
+#[test]
+fn test_pivoted_cholesky_rank_deficient() {
+    let b = MatrixBuilder::new().from_mat(
+        vec![
+            vec![1f64, 0f64],
+            vec![0.6f64, 0.8f64],
+            vec![0.28f64, 0.96f64],
+            vec![-0.8f64, 0.6f64],
+        ]
+    ).build().unwrap();
+    let a = &b * &b.transpose();
+    assert!(a.cholesky().is_err());
+    assert!(a.is_positive_semi_definite());
+    let factor = a.pivoted_cholesky(None).unwrap();
+    assert_eq!(factor.rank(), 2);
+    assert_eq!(factor.l().rows(), 4);
+    assert_matrix_approx_eq!(factor.l() * &factor.l().transpose(), permuted(&a, factor.permutation()), Tolerance::Absolute(1e-12));
+    let f = factor.factor();
+    assert_matrix_approx_eq!(&f * &f.transpose(), a, Tolerance::Absolute(1e-12));
+    let x = factor.correlate(&[1f64, -2f64]).unwrap();
+    assert!((0..4).all(|i| (x[i] - (f[(i, 0)] - 2f64 * f[(i, 1)])).abs() < 1e-15));
+    assert_eq!(factor.correlate(&[1f64]).unwrap_err(), CholeskyDecompositionError::DimensionMismatch);
+}
+
+#[test]
+fn test_pivoted_cholesky_full_rank_and_indefinite() {
+    let n = 5;
+    let x = pseudo_random_matrix(n, n, 23);
+    let a = &(&x * &x.transpose()) + &MatrixBuilder::<f64>::new().identity(n).build().unwrap();
+    let factor = a.pivoted_cholesky(None).unwrap();
+    assert_eq!(factor.rank(), n);
+    assert_matrix_approx_eq!(factor.l() * &factor.l().transpose(), permuted(&a, factor.permutation()), Tolerance::Absolute(1e-12));
+    assert!(factor.l().data().windows(n + 1).step_by(n + 1).all(|w| w[0] >= w[n]));
+
+    let a = MatrixBuilder::new().from_mat(
+        vec![
+            vec![2f64, 1f64],
+            vec![1f64, -3f64],
+        ]
+    ).build().unwrap();
+    assert!(!a.is_positive_semi_definite());
+    assert!(matches!(a.pivoted_cholesky(None), Err(CholeskyDecompositionError::LeadingMinorIsNotPositive { index: 1, .. })));
+
+    let zero = MatrixBuilder::<f64>::new().zeros(2, 2).build().unwrap();
+    assert!(zero.is_positive_semi_definite());
+    let factor = zero.pivoted_cholesky(None).unwrap();
+    assert_eq!((factor.rank(), factor.l().rows()), (0, 2));
+    assert_eq!(factor.permutation(), &[0, 1]);
+    assert_eq!(factor.correlate(&[]).unwrap(), vec![0f64, 0f64]);
+
+    // Zero trailing diagonal with a non zero off-diagonal block
+    let a = MatrixBuilder::new().from_mat(
+        vec![
+            vec![1f64, 0f64, 0f64],
+            vec![0f64, 0f64, 1f64],
+            vec![0f64, 1f64, 0f64],
+        ]
+    ).build().unwrap();
+    assert!(!a.is_positive_semi_definite());
+    assert_eq!(a.pivoted_cholesky(None), Err(CholeskyDecompositionError::MatrixIsNotPositiveDefinite));
+}
+
+#[test]
+fn test_nearest_correlation() {
+    // Example from Higham (2002), section 4
+    let a = MatrixBuilder::new().from_mat(
+        vec![
+            vec![2f64, -1f64, 0f64, 0f64],
+            vec![-1f64, 2f64, -1f64, 0f64],
+            vec![0f64, -1f64, 2f64, -1f64],
+            vec![0f64, 0f64, -1f64, 2f64],
+        ]
+    ).build().unwrap();
+    let expected = MatrixBuilder::new().from_mat(
+        vec![
+            vec![1f64, -0.8084f64, 0.1916f64, 0.1068f64],
+            vec![-0.8084f64, 1f64, -0.6562f64, 0.1916f64],
+            vec![0.1916f64, -0.6562f64, 1f64, -0.8084f64],
+            vec![0.1068f64, 0.1916f64, -0.8084f64, 1f64],
+        ]
+    ).build().unwrap();
+    let repair = a.nearest_correlation(None).unwrap();
+    assert_matrix_approx_eq!(repair.matrix(), expected, Tolerance::Absolute(1e-4));
+    assert!((repair.distance() - 2.1337f64).abs() < 1e-4);
+    let (d, _) = repair.matrix().symmetrize().unwrap().eigen_decomposition(Algorithm::Jacobi).unwrap();
+    assert!(d.iter().all(|&x| x > -1e-8));
+
+    let weighted = a.nearest_correlation(Some(&[1f64, 1f64, 1f64, 1f64])).unwrap();
+    assert_matrix_approx_eq!(weighted.matrix(), repair.matrix(), Tolerance::Absolute(1e-8));
+    let weighted = a.nearest_correlation(Some(&[100f64, 1f64, 1f64, 100f64])).unwrap();
+    assert!((0..4).all(|i| weighted.matrix()[(i, i)] == 1f64));
+    assert!((weighted.matrix()[(0, 3)] - a[(0, 3)]).abs() < (repair.matrix()[(0, 3)] - a[(0, 3)]).abs());
+
+    assert_eq!(a.nearest_correlation(Some(&[1f64])).unwrap_err(), NearestCorrelationError::DimensionMismatch);
+    assert_eq!(a.nearest_correlation(Some(&[1f64, 0f64, 1f64, 1f64])).unwrap_err(), NearestCorrelationError::WeightIsNotPositive);
+    let b = MatrixBuilder::new().from_vec(2, 2, vec![1f64, 0.5f64, 0f64, 1f64]).build().unwrap();
+    assert_eq!(b.nearest_correlation(None).unwrap_err(), NearestCorrelationError::MatrixIsNotSymmetric);
+}
+
+#[test]
+fn test_clip_to_correlation() {
+    let a = MatrixBuilder::new().from_mat(
+        vec![
+            vec![1f64, 0.9f64, 0.7f64],
+            vec![0.9f64, 1f64, 0.3f64],
+            vec![0.7f64, 0.3f64, 1f64],
+        ]
+    ).build().unwrap();
+    assert!(a.cholesky().is_err());
+    let repair = a.clip_to_correlation(1e-6).unwrap();
+    assert!((0..3).all(|i| (repair.matrix()[(i, i)] - 1f64).abs() < 1e-15));
+    assert!(repair.matrix().symmetrize().unwrap().cholesky().is_ok());
+    assert!(repair.distance() > 0f64);
+    assert!(repair.distance() >= a.nearest_correlation(None).unwrap().distance() - 1e-8);
+
+    let identity = MatrixBuilder::<f64>::new().identity(3).build().unwrap();
+    assert!(identity.clip_to_correlation(1e-6).unwrap().distance() < 1e-15);
+    for floor in [0f64, -1f64, f64::NAN, f64::INFINITY] {
+        assert_eq!(identity.clip_to_correlation(floor).unwrap_err(), NearestCorrelationError::FloorIsNotPositive);
+    }
+}
+
+#[test]
+fn test_modified_cholesky() {
+    let a = MatrixBuilder::new().from_mat(
+        vec![
+            vec![4f64, 2f64, 0.4f64],
+            vec![2f64, 2f64, 1f64],
+            vec![0.4f64, 1f64, 3f64],
+        ]
+    ).build().unwrap();
+    let modified = a.modified_cholesky().unwrap();
+    assert!(!modified.is_perturbed());
+    assert_matrix_approx_eq!(modified.l(), a.cholesky().unwrap().l(), Tolerance::Absolute(1e-15));
+
+    let a = MatrixBuilder::new().from_mat(
+        vec![
+            vec![1f64, 1f64, 2f64],
+            vec![1f64, 1f64, 3f64],
+            vec![2f64, 3f64, 1f64],
+        ]
+    ).build().unwrap();
+    assert!(a.cholesky().is_err());
+    let modified = a.modified_cholesky().unwrap();
+    assert!(modified.is_perturbed());
+    assert!(modified.e().iter().all(|&x| x >= 0f64));
+    let mut perturbed = a.clone();
+    for (i, &e) in modified.e().iter().enumerate() { perturbed[(i, i)] += e; }
+    assert_matrix_approx_eq!(modified.l() * &modified.l().transpose(), perturbed, Tolerance::Absolute(1e-12));
+    assert!(perturbed.cholesky().is_ok());
+    let b = MatrixBuilder::new().from_vec(3, 1, vec![1f64, 0f64, -1f64]).build().unwrap();
+    assert_matrix_approx_eq!(&perturbed * &modified.factor().solve(&b).unwrap(), b, Tolerance::Absolute(1e-10));
+
+    let x = pseudo_random_matrix(6, 6, 31);
+    let a = &x + &x.transpose();
+    let modified = a.modified_cholesky().unwrap();
+    let mut perturbed = a.clone();
+    for (i, &e) in modified.e().iter().enumerate() { perturbed[(i, i)] += e; }
+    assert_matrix_approx_eq!(modified.l() * &modified.l().transpose(), perturbed, Tolerance::Absolute(1e-12));
+
+    let b = MatrixBuilder::new().from_vec(2, 2, vec![1f64, 0.5f64, 0f64, 1f64]).build().unwrap();
+    assert_eq!(b.modified_cholesky().unwrap_err(), CholeskyDecompositionError::MatrixIsNotSymmetric);
+    let wide = MatrixBuilder::new().from_vec(2, 3, vec![1f64; 6]).build().unwrap();
+    assert_eq!(wide.modified_cholesky().unwrap_err(), CholeskyDecompositionError::MatrixIsNotSymmetric);
+
+    let mut hessian = a.clone();
+    hessian[(0, 1)] = f64::from_bits(hessian[(0, 1)].to_bits() + 1);
+    assert!(!hessian.is_symmetric());
+    let modified = hessian.modified_cholesky().unwrap();
+    let mut perturbed = hessian.symmetrize().unwrap();
+    for (i, &e) in modified.e().iter().enumerate() { perturbed[(i, i)] += e; }
+    assert_matrix_approx_eq!(modified.l() * &modified.l().transpose(), perturbed, Tolerance::Absolute(1e-12));
+    assert_eq!(hessian.modified_cholesky_within(Tolerance::Absolute(0f64)).unwrap_err(), CholeskyDecompositionError::MatrixIsNotSymmetric);
+}
+
+#[test]
+fn test_cholesky_update_and_downdate() {
+    let n = 5;
+    let x = pseudo_random_matrix(n, n, 37);
+    let a = &(&x * &x.transpose()) + &MatrixBuilder::<f64>::new().identity(n).build().unwrap();
+    let v = pseudo_random_matrix(n, 1, 41);
+    let vvt = &v * &v.transpose();
+
+    let mut factor = a.cholesky().unwrap();
+    factor.update(v.data()).unwrap();
+    let updated = &a + &vvt;
+    assert_matrix_approx_eq!(factor.l(), updated.cholesky().unwrap().l(), Tolerance::Absolute(1e-12));
+
+    factor.downdate(v.data()).unwrap();
+    assert_matrix_approx_eq!(factor.l(), a.cholesky().unwrap().l(), Tolerance::Absolute(1e-12));
+
+    let big: Vec<f64> = vec![10f64; n];
+    let before = factor.clone();
+    assert!(matches!(factor.downdate(&big), Err(CholeskyDecompositionError::LeadingMinorIsNotPositive { index: 0, .. })));
+    assert_eq!(factor, before);
+    assert_eq!(factor.update(&[1i32, 2i32]).unwrap_err(), CholeskyDecompositionError::DimensionMismatch);
+}
+
+#[test]
+fn test_cholesky_blocked() {
+    let n = 11;
+    let x = pseudo_random_matrix(n, n, 43);
+    let a = &(&x * &x.transpose()) + &MatrixBuilder::<f64>::new().identity(n).build().unwrap();
+    let expected = a.cholesky().unwrap();
+    for block_size in [0, 1, 3, 4, 11, 64] {
+        assert_matrix_approx_eq!(a.cholesky_blocked(block_size).unwrap().l(), expected.l(), Tolerance::Absolute(1e-13));
+    }
+    assert!(a.view().cholesky_blocked(4).unwrap().l().data().iter().zip(expected.l().data()).all(|(x, y)| (x - y).abs() < 1e-13));
+
+    let mut b = a.clone();
+    b[(7, 7)] = -1f64;
+    for block_size in [1, 3, 8] {
+        assert!(matches!(b.cholesky_blocked(block_size), Err(CholeskyDecompositionError::LeadingMinorIsNotPositive { index: 7, .. })));
+    }
+    let c = MatrixBuilder::new().from_vec(2, 2, vec![1f64, 0.5f64, 0f64, 1f64]).build().unwrap();
+    assert_eq!(c.cholesky_blocked(2).unwrap_err(), CholeskyDecompositionError::MatrixIsNotPositiveDefinite);
+}
+
+#[test]
+fn test_large_matmul_cholesky_and_svd() {
+    // Large enough to take the threaded paths when the parallel feature is enabled
+    let a = pseudo_random_matrix(96, 80, 47);
+    let b = pseudo_random_matrix(80, 72, 53);
+    let c = &a * &b;
+    assert!((0..96).all(|i| (0..72).all(|j| c[(i, j)] == (0..80).fold(0f64, |acc, k| acc + a[(i, k)] * b[(k, j)]))));
+
+    let n = 120;
+    let x = pseudo_random_matrix(n, n, 59);
+    let spd = &(&x * &x.transpose()) + &MatrixBuilder::<f64>::new().identity(n).build().unwrap();
+    assert_matrix_approx_eq!(spd.cholesky_blocked(16).unwrap().l(), spd.cholesky().unwrap().l(), Tolerance::Absolute(1e-12));
+
+    let svd = a.svd().unwrap();
+    assert_matrix_approx_eq!(&(svd.thin_u() * &svd.sigma()) * &svd.thin_v().transpose(), a, Tolerance::Absolute(1e-12));
+    assert_matrix_approx_eq!(&svd.thin_v().transpose() * svd.thin_v(), MatrixBuilder::<f64>::new().identity(80).build().unwrap(), Tolerance::Absolute(1e-12));
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn test_parallel_results_do_not_depend_on_thread_count() {
+    let a = pseudo_random_matrix(96, 80, 61);
+    let x = pseudo_random_matrix(100, 100, 67);
+    let spd = &(&x * &x.transpose()) + &MatrixBuilder::<f64>::new().identity(100).build().unwrap();
+    let run = |threads: usize| {
+        let mut l = spd.clone();
+        crate::definite::factorize_blocked(l.data_mut(), 100, 8, threads).unwrap();
+        let svd = crate::svd::one_sided_jacobi(a.clone(), threads).unwrap();
+        let eigen: (Vec<f64>, Matrix<f64>) = crate::symmetric::jacobi(&spd, threads).unwrap();
+        (a.matmul_on(&a.transpose(), threads).unwrap(), l, svd.singular_values().to_vec(), eigen)
+    };
+    let (serial, parallel) = (run(1), run(4));
+    assert_eq!(serial.0, parallel.0);
+    assert_eq!(serial.1, parallel.1);
+    assert_eq!(serial.2, parallel.2);
+    // Rows of V see the same rotations in the same order whatever the thread count
+    assert_eq!(serial.3, parallel.3);
+    assert_eq!(serial.3, spd.eigen_decomposition(Algorithm::Jacobi).unwrap());
+}
+
+#[test]
+fn test_f32_eigen_decomposition_and_cholesky() {
+    let a = MatrixBuilder::new().from_mat(
+        vec![
+            vec![4f32, 1f32, 2f32],
+            vec![1f32, 3f32, 0.5f32],
+            vec![2f32, 0.5f32, 5f32],
+        ]
+    ).build().unwrap();
+    let (expected, _) = a.eigen_decomposition(Algorithm::Jacobi).unwrap();
+    for algorithm in [Algorithm::Jacobi, Algorithm::TridiagonalQL] {
+        let (d, v): (Vec<f32>, Matrix<f32>) = a.eigen_decomposition_as(algorithm).unwrap();
+        assert!(d.iter().zip(&expected).all(|(&x, &y)| (x as f64 - y).abs() < 1e-5));
+        for k in 0..3 {
+            for i in 0..3 {
+                let av: f32 = (0..3).map(|j| a[(i, j)] * v[(j, k)]).sum();
+                assert!((av - d[k] * v[(i, k)]).abs() < 1e-5);
+            }
+        }
+    }
+
+    let factor: CholeskyFactor<f32> = a.cholesky_as().unwrap();
+    let expected = a.cholesky().unwrap();
+    assert!(factor.l().data().iter().zip(expected.l().data()).all(|(&x, &y)| (x as f64 - y).abs() < 1e-6));
+    assert!((factor.determinant() as f64 - expected.determinant()).abs() < 1e-4);
+    let x = factor.solve_vector(&[1i32, 2i32, 3i32]).unwrap();
+    assert!((0..3).all(|i| ((0..3).map(|j| a[(i, j)] * x[j]).sum::<f32>() - (i + 1) as f32).abs() < 1e-5));
+
+    let b = MatrixBuilder::new().from_vec(2, 2, vec![1i32, 2i32, 2i32, 1i32]).build().unwrap();
+    assert!(matches!(b.cholesky_as::<f32>(), Err(CholeskyDecompositionError::LeadingMinorIsNotPositive { index: 1, .. })));
+}
+
+#[test]
+fn test_complex_numeric() {
+    let a = Complex::new(1f64, 2f64);
+    let b = Complex::new(3f64, -1f64);
+    assert_eq!(a + b, Complex::new(4f64, 1f64));
+    assert_eq!(a - b, Complex::new(-2f64, 3f64));
+    assert_eq!(a * b, Complex::new(5f64, 5f64));
+    assert_eq!((a * b) / b, a);
+    assert_eq!(-a, Complex::new(-1f64, -2f64));
+    assert_eq!(Complex::<f64>::from_i32(3), Complex::new(3f64, 0f64));
+    assert_eq!(a.to::<f64>(), 1f64);
+    assert_eq!(a.to::<Complex<f32>>(), Complex::new(1f32, 2f32));
+    assert_eq!(2.5f32.to::<Complex<f64>>(), Complex::new(2.5f64, 0f64));
+    assert!(Complex::new(1f64, 0f64) < Complex::new(2f64, 0f64));
+    assert_eq!(a.partial_cmp(&b), None);
+    assert!((Complex::new(3f32, 4f32).norm() - 5f32).abs() < 1e-6);
+
+    let m = MatrixBuilder::new().from_vec(2, 2, vec![a, b, b, a]).build().unwrap();
+    let identity = MatrixBuilder::<Complex<f64>>::new().identity(2).build().unwrap();
+    assert_eq!(&m * &identity, m);
+    let h = m.conjugate_transpose();
+    assert_eq!(h[(0, 1)], b.conj());
+    assert_eq!(h.conjugate_transpose(), m);
+}
+
+#[test]
+fn test_hermitian_cholesky() {
+    let i = |re: f64, im: f64| Complex::new(re, im);
+    let a = MatrixBuilder::new().from_mat(
+        vec![
+            vec![i(4f64, 0f64), i(1f64, 2f64), i(0f64, -1f64)],
+            vec![i(1f64, -2f64), i(6f64, 0f64), i(2f64, 1f64)],
+            vec![i(0f64, 1f64), i(2f64, -1f64), i(5f64, 0f64)],
+        ]
+    ).build().unwrap();
+    assert!(a.is_hermitian());
+    assert_ne!(a, a.transpose());
+    assert!(a.view().is_hermitian_within(Tolerance::Absolute(0f64)));
+    let factor = a.hermitian_cholesky().unwrap();
+    let l = factor.l();
+    assert!((0..3).all(|k| l[(k, k)].is_real() && l[(k, k)].re > 0f64));
+    let product = l * &l.conjugate_transpose();
+    assert!(product.data().iter().zip(a.data()).all(|(x, y)| (*x - *y).norm() < 1e-12));
+
+    let b = MatrixBuilder::new().from_vec(3, 1, vec![i(1f64, 0f64), i(0f64, 1f64), i(-1f64, 1f64)]).build().unwrap();
+    let x = factor.solve(&b).unwrap();
+    assert!((&a * &x).data().iter().zip(b.data()).all(|(x, y)| (*x - *y).norm() < 1e-12));
+    let det = a[(0, 0)] * (a[(1, 1)] * a[(2, 2)] - a[(1, 2)] * a[(2, 1)])
+        - a[(0, 1)] * (a[(1, 0)] * a[(2, 2)] - a[(1, 2)] * a[(2, 0)])
+        + a[(0, 2)] * (a[(1, 0)] * a[(2, 1)] - a[(1, 1)] * a[(2, 0)]);
+    assert!(det.im.abs() < 1e-12);
+    let det = det.re;
+    assert!((factor.determinant() - det).abs() < 1e-9);
+
+    let mut not_hermitian = a.clone();
+    not_hermitian[(0, 1)] = i(1f64, -2f64);
+    assert!(!not_hermitian.is_hermitian());
+    assert_eq!(not_hermitian.hermitian_cholesky().unwrap_err(), CholeskyDecompositionError::MatrixIsNotPositiveDefinite);
+    let mut indefinite = a.clone();
+    indefinite[(2, 2)] = i(-1f64, 0f64);
+    assert!(!indefinite.is_hermitian_positive_definite());
+    assert!(matches!(indefinite.hermitian_cholesky(), Err(CholeskyDecompositionError::LeadingMinorIsNotPositive { index: 2, .. })));
+}
+
+#[test]
+fn test_rational_arithmetic() {
+    let r = |n: i128, d: i128| Rational::new(n, d).unwrap();
+    assert_eq!(r(2, -4), r(-1, 2));
+    assert_eq!((r(2, -4).numerator(), r(2, -4).denominator()), (-1, 2));
+    assert_eq!(Rational::new(1, 0).unwrap_err(), RationalError::ZeroDenominator);
+    assert_eq!(r(1, 3) + r(1, 6), r(1, 2));
+    assert_eq!(r(1, 3) - r(1, 2), r(-1, 6));
+    assert_eq!(r(2, 3) * r(9, 4), r(3, 2));
+    assert_eq!(r(2, 3) / r(4, 9), r(3, 2));
+    assert_eq!(r(1, 3).checked_div(&Rational::zero()).unwrap_err(), RationalError::ZeroDenominator);
+    assert!(r(1, 3) < r(1, 2));
+    assert!(r(-7, 3) < r(-2, 1));
+    assert!(r(355, 113) < r(22, 7));
+    assert!(r(i128::MAX, i128::MAX - 1) < r(i128::MAX - 1, i128::MAX - 2));
+    assert_eq!(format!("{} {}", r(-3, 4), r(5, 1)), "-3/4 5");
+
+    let big = Rational::integer(i128::MAX).unwrap();
+    assert_eq!(big.checked_add(&Rational::one()).unwrap_err(), RationalError::Overflow);
+    assert_eq!(big.checked_mul(&r(2, 1)).unwrap_err(), RationalError::Overflow);
+    assert_eq!(big.checked_mul(&r(1, 2)).unwrap(), r(i128::MAX, 2));
+
+    assert_eq!(Rational::from_f64(0.75f64), r(3, 4));
+    assert_eq!(Rational::from_f64(-1e-3f64).to::<f64>(), -1e-3f64);
+    assert_eq!(Rational::from_f64(2f64.powi(100)), Rational::integer(1i128 << 100).unwrap());
+    assert_eq!(Rational::from_f64(f64::INFINITY), Rational::integer(i128::MAX).unwrap());
+    assert_eq!(Rational::from_f32(0.1f32).to::<f32>(), 0.1f32);
+    assert_eq!(r(7, 1).to::<i64>(), 7i64);
+    assert_eq!(r(1, 3).to::<f64>(), 1f64 / 3f64);
+    assert_eq!(5i32.to::<Rational>(), r(5, 1));
+
+    // Same entries as test_is_positive_definite, without 1i64 / 3i64 truncating to zero
+    let a = MatrixBuilder::new().from_mat(
+        vec![
+            vec![r(4, 1), r(1, 3), r(2, 3)],
+            vec![r(1, 3), r(5, 1), r(3, 1)],
+            vec![r(2, 3), r(3, 1), r(6, 1)],
+        ]
+    ).build().unwrap();
+    assert!(a.is_symmetric());
+    assert!(a.is_positive_definite());
+    assert_eq!(a.exact_determinant().unwrap(), r(742, 9));
+}
+
+#[test]
+fn test_exact_elimination() {
+    let r = |n: i128, d: i128| Rational::new(n, d).unwrap();
+    // Hilbert matrix, hopeless in floating point as n grows but exact here
+    let n = 6;
+    let mut hilbert = MatrixBuilder::<Rational>::new().zeros(n, n).build().unwrap();
+    for i in 0..n {
+        for j in 0..n {
+            hilbert[(i, j)] = r(1, (i + j + 1) as i128);
+        }
+    }
+    assert!(hilbert.is_symmetric());
+    assert_eq!(hilbert.exact_determinant().unwrap(), r(1, 186313420339200000));
+    let lu = hilbert.exact_lu().unwrap();
+    assert_eq!(&lu.l() * &lu.u(), &lu.p() * &hilbert);
+    let inverse = lu.inverse().unwrap();
+    assert_eq!(&hilbert * &inverse, MatrixBuilder::<Rational>::new().identity(n).build().unwrap());
+    assert!(inverse.data().iter().all(|x| x.is_integer()));
+    assert_eq!(inverse[(0, 0)], r(36, 1));
+
+    let a = MatrixBuilder::new().from_mat(
+        vec![
+            vec![4i64, 1i64, 2i64],
+            vec![1i64, 5i64, 3i64],
+            vec![2i64, 3i64, 6i64],
+        ]
+    ).build().unwrap();
+    assert_eq!(a.exact_determinant().unwrap(), r(70, 1));
+    let x = a.exact_lu().unwrap().solve(&MatrixBuilder::new().from_vec(3, 1, vec![1i64, 0i64, 0i64]).build().unwrap()).unwrap();
+    assert_eq!(x.data(), &vec![r(3, 10), r(0, 1), r(-1, 10)]);
+    assert_eq!(a.exact_rank().unwrap(), 3);
+
+    let singular = MatrixBuilder::new().from_mat(
+        vec![
+            vec![1f64, 2f64, 3f64, 4f64],
+            vec![0.5f64, 1f64, 1.5f64, 2f64],
+            vec![0.25f64, 0.5f64, 0.75f64, 1f64],
+        ]
+    ).build().unwrap();
+    assert_eq!(singular.exact_rank().unwrap(), 1);
+    // 0.1 + 0.2 is not exactly three times 0.1 in binary, which exact rank does see
+    let tenths = MatrixBuilder::new().from_vec(2, 3, vec![1f64, 2f64, 3f64, 0.1f64, 0.2f64, 0.1f64 + 0.2f64]).build().unwrap();
+    assert_eq!(tenths.exact_rank().unwrap(), 2);
+    assert_eq!(singular.transpose().exact_rank().unwrap(), 1);
+    assert_eq!(singular.exact_determinant().unwrap_err(), ExactEliminationError::MatrixIsNotSquare);
+    let square = singular.submatrix(.., ..3).unwrap();
+    assert_eq!(square.exact_determinant().unwrap(), Rational::zero());
+    assert_eq!(square.exact_lu().unwrap().solve(&square).unwrap_err(), ExactEliminationError::MatrixIsSingular);
+
+    let big = MatrixBuilder::new().from_mat(
+        vec![
+            vec![Rational::integer(i128::MAX / 2).unwrap(), Rational::one()],
+            vec![Rational::one(), Rational::integer(i128::MAX / 2).unwrap()],
+        ]
+    ).build().unwrap();
+    assert_eq!(big.exact_determinant().unwrap_err(), ExactEliminationError::Overflow);
+
+    let mut nan = MatrixBuilder::<f64>::new().identity(2).build().unwrap();
+    nan[(1, 0)] = f64::NAN;
+    assert!(matches!(
+        nan.exact_determinant().unwrap_err(),
+        ExactEliminationError::ValueIsNotRepresentable(NumericConversionError { position: Some((1, 0)), .. })
+    ));
+    nan[(1, 0)] = 1e-300f64;
+    assert!(matches!(nan.exact_rank().unwrap_err(), ExactEliminationError::ValueIsNotRepresentable(_)));
+    let rhs = MatrixBuilder::new().from_vec(3, 1, vec![1f64, f64::INFINITY, 0f64]).build().unwrap();
+    assert!(matches!(
+        a.exact_lu().unwrap().solve(&rhs).unwrap_err(),
+        ExactEliminationError::ValueIsNotRepresentable(NumericConversionError { position: Some((1, 0)), .. })
+    ));
+    assert_eq!(2f64.powi(-126).try_to::<Rational>().unwrap(), Rational::new(1, 1i128 << 126).unwrap());
+    assert!(2f64.powi(-127).try_to::<Rational>().is_err());
+}
+
+#[test]
+fn test_checked_conversions() {
+    assert_eq!(7i64.try_to::<i32>().unwrap(), 7i32);
+    assert_eq!((i32::MAX as i64 + 1).try_to::<i32>().unwrap_err().value, "2147483648");
+    assert!(f64::NAN.try_to::<i32>().is_err());
+    assert!(f64::INFINITY.try_to::<i64>().is_err());
+    assert_eq!((-3.9f64).try_to::<i32>().unwrap(), -3i32);
+    assert!(1e300f64.try_to::<f32>().is_err());
+    assert!(f64::INFINITY.try_to::<f32>().unwrap().is_infinite());
+    assert_eq!(Complex::new(2f64, 0f64).try_to::<f64>().unwrap(), 2f64);
+    assert!(Complex::new(2f64, 1f64).try_to::<f64>().is_err());
+    assert_eq!(Rational::new(6, 2).unwrap().try_to::<i32>().unwrap(), 3i32);
+
+    let a = MatrixBuilder::new().from_vec(2, 2, vec![1i64, 2i64, 3i64, 1i64 << 40]).build().unwrap();
+    assert_eq!(
+        a.copy_to_checked::<i32>().unwrap_err(),
+        NumericConversionError { value: "1099511627776".to_string(), position: Some((1, 1)) }
+    );
+    assert_eq!(a.submatrix(..1, ..).unwrap().copy_to_checked::<i32>().unwrap().data(), &vec![1i32, 2i32]);
+}
+
+#[test]
+fn test_checked_arithmetic() {
+    let a = MatrixBuilder::new().from_vec(2, 2, vec![1i32, 2i32, 3i32, i32::MAX]).build().unwrap();
+    let b = MatrixBuilder::new().from_vec(2, 2, vec![1i32, 1i32, 1i32, 1i32]).build().unwrap();
+    assert_eq!(a.try_add(&b).unwrap_err(), MatrixArithmeticError::Overflow { position: (1, 1) });
+    assert_eq!(a.try_sub(&b).unwrap().data(), &vec![0i32, 1i32, 2i32, i32::MAX - 1]);
+    assert_eq!(a.try_scale(2i32).unwrap_err(), MatrixArithmeticError::Overflow { position: (1, 1) });
+    assert_eq!(a.try_matmul(&b).unwrap_err(), MatrixArithmeticError::Overflow { position: (1, 0) });
+    assert_eq!(b.try_matmul(&b).unwrap(), &b + &b);
+
+    let c = MatrixBuilder::new().from_vec(1, 2, vec![1i64, 2i64]).build().unwrap();
+    let d = MatrixBuilder::new().from_vec(2, 1, vec![3i64, 4i64]).build().unwrap();
+    assert_eq!(c.try_matmul(&d).unwrap().data(), &vec![11i64]);
+    assert!(matches!(c.try_add(&d).unwrap_err(), MatrixArithmeticError::DimensionMismatch(_)));
+    assert!(matches!(c.try_matmul(&c).unwrap_err(), MatrixArithmeticError::DimensionMismatch(_)));
+}
+
+#[test]
+fn test_integer_round_trips() {
+    fn round_trip<T, S>(values: &[T]) where T: Numeric, S: Numeric {
+        for value in values {
+            assert_eq!(value.to::<S>().to::<T>(), *value);
+            assert_eq!(value.try_to::<S>().unwrap().try_to::<T>().unwrap(), *value);
+        }
+    }
+    round_trip::<u8, i16>(&[0, 1, u8::MAX]);
+    round_trip::<i8, i64>(&[i8::MIN, -1, 0, i8::MAX]);
+    round_trip::<u16, u32>(&[0, u16::MAX]);
+    round_trip::<u32, i64>(&[0, u32::MAX]);
+    round_trip::<u64, u128>(&[0, u64::MAX, u64::MAX - 1]);
+    round_trip::<i64, i128>(&[i64::MIN, i64::MAX]);
+    round_trip::<i128, Rational>(&[-i128::MAX, i128::MAX, 1 << 100]);
+    round_trip::<u64, Rational>(&[u64::MAX]);
+    round_trip::<usize, u64>(&[0, usize::MAX]);
+    round_trip::<isize, i128>(&[isize::MIN, isize::MAX]);
+    round_trip::<u32, f64>(&[u32::MAX]);
+    round_trip::<i32, Complex<f64>>(&[i32::MIN, i32::MAX]);
+
+    assert!(256u32.try_to::<u8>().is_err());
+    assert!((-1i8).try_to::<u64>().is_err());
+    assert!(u128::MAX.try_to::<i128>().is_err());
+    assert!(u128::MAX.try_to::<Rational>().is_err());
+    assert!(u128::MAX.try_to::<f32>().is_err());
+    assert_eq!(u128::MAX.try_to::<f64>().unwrap(), 2f64.powi(128));
+    assert!(i128::MIN.try_to::<Rational>().is_err());
+    assert_eq!(255.9f64.try_to::<u8>().unwrap(), 255u8);
+    assert!(256f64.try_to::<u8>().is_err());
+    assert_eq!((-0.5f64).try_to::<u8>().unwrap(), 0u8);
+    assert!(2f64.powi(64).try_to::<u64>().is_err());
+    assert_eq!(Rational::integer(u64::MAX as i128).unwrap().to::<u64>(), u64::MAX);
+
+    let pixels = MatrixBuilder::new().from_vec(2, 2, vec![0u8, 17u8, 200u8, 255u8]).build().unwrap();
+    assert_eq!(pixels.copy_to::<f64>().unwrap().copy_to_checked::<u8>().unwrap(), pixels);
+    assert_eq!(pixels.try_add(&pixels).unwrap_err(), MatrixArithmeticError::Overflow { position: (1, 0) });
+    let counts = MatrixBuilder::new().from_vec(1, 2, vec![u64::MAX, 1u64]).build().unwrap();
+    assert_eq!(counts.copy_to::<i128>().unwrap().copy_to::<u64>().unwrap(), counts);
+    assert_eq!(counts.copy_to_checked::<i64>().unwrap_err().position, Some((0, 0)));
+}
+
+#[test]
+fn test_approximate_equality() {
+    assert!(Tolerance::Ulps(1).is_within(1f64, 1f64 + f64::EPSILON));
+    assert!(!Tolerance::Ulps(1).is_within(1f64, 1f64 + 2f64 * f64::EPSILON));
+    assert!(Tolerance::Ulps(2).is_within(-f64::from_bits(1), f64::from_bits(1)));
+    assert!(Tolerance::Ulps(0).is_within(0f64, -0f64));
+    assert!(!Tolerance::Ulps(u64::MAX).is_within(f64::NAN, f64::NAN));
+    assert!(!Tolerance::Ulps(1 << 62).is_within(-1f64, 1f64));
+
+    let a = MatrixBuilder::new().from_vec(2, 2, vec![1f64, 2f64, 3f64, 4f64]).build().unwrap();
+    let b = MatrixBuilder::new().from_vec(2, 2, vec![1f64, 2.001f64, 3f64, 4.0005f64]).build().unwrap();
+    assert!(a.approx_eq(&b, Tolerance::Absolute(1e-3)));
+    assert!(!a.approx_eq(&b, Tolerance::Absolute(1e-4)));
+    assert!(a.approx_eq(&b, Tolerance::Relative(5e-4)));
+    assert!(!a.approx_eq(&b, Tolerance::Ulps(1000)));
+    assert!(!a.approx_eq(&a.transpose().submatrix(.., ..1).unwrap(), Tolerance::Absolute(1f64)));
+    let worst = a.max_abs_diff(&b).unwrap();
+    assert_eq!(worst.position, (0, 1));
+    assert!((worst.difference - 1e-3).abs() < 1e-12);
+    assert_eq!(a.max_abs_diff(&a.transpose().transpose()).unwrap().difference, 0f64);
+    assert_eq!(a.max_abs_diff(&a.transpose().submatrix(.., ..1).unwrap()).unwrap_err(), MatrixDimensionMismatchError { left: (2, 2), right: (2, 1) });
+
+    // One f32 ulp is 2^29 f64 ulps, matrices of f32 are compared in f32 units
+    let single = a.copy_to::<f32>().unwrap();
+    let mut next = single.clone();
+    next[(1, 1)] = f32::from_bits(4f32.to_bits() + 1);
+    assert!(single.approx_eq(&next, Tolerance::Ulps(1)));
+    assert!(!single.approx_eq(&next, Tolerance::Ulps(0)));
+    assert!(next.approx_eq(&a, Tolerance::Ulps(1)));
+    assert!(!next.copy_to::<f64>().unwrap().approx_eq(&a, Tolerance::Ulps(1000)));
+    let integers = MatrixBuilder::new().from_vec(2, 2, vec![1i32, 2i32, 3i32, 4i32]).build().unwrap();
+    assert!(integers.approx_eq(&a, Tolerance::Ulps(0)));
+    assert!(integers.copy_to::<f32>().unwrap().approx_eq(&a, Tolerance::Ulps(0)));
+    let z = MatrixBuilder::new().from_vec(1, 2, vec![Complex::new(1f64, 1f64), Complex::new(0f64, 0f64)]).build().unwrap();
+    let w = MatrixBuilder::new().from_vec(1, 2, vec![Complex::new(1f64, 0f64), Complex::new(3f64, 4f64)]).build().unwrap();
+    assert!(!z.approx_eq(&w.transpose().transpose(), Tolerance::Absolute(0.5f64)));
+    assert_eq!(z.max_abs_diff(&w).unwrap(), ElementDifference { position: (0, 1), difference: 5f64 });
+
+    let nan = MatrixBuilder::new().from_vec(1, 2, vec![f64::NAN, 100f64]).build().unwrap();
+    let zeros = MatrixBuilder::<f64>::new().zeros(1, 2).build().unwrap();
+    assert_eq!(nan.max_abs_diff(&zeros).unwrap().position, (0, 0));
+
+    let message = std::panic::catch_unwind(|| assert_matrix_approx_eq!(a, b, Tolerance::Absolute(1e-4))).unwrap_err();
+    let message = message.downcast_ref::<String>().unwrap();
+    assert!(message.contains("difference:"));
+    assert!(message.contains("at (0, 1)"));
 }
@@ -0,0 +1,46 @@
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Tolerance {
+    Absolute(f64),
+    Relative(f64),
+    // Units in the last place of f64, or of the coarser element type when matrices are compared
+    Ulps(u64),
+}
+
+impl Tolerance {
+
+    pub fn is_within(&self, a: f64, b: f64) -> bool {
+        self.is_within_digits(a, b, f64::MANTISSA_DIGITS)
+    }
+
+    // Ulps are counted between a and b rounded to f32 when digits is at most its 24 bit significand
+    pub(crate) fn is_within_digits(&self, a: f64, b: f64, digits: u32) -> bool {
+        if a == b { return true }
+        let difference = (a - b).abs();
+        match *self {
+            Tolerance::Absolute(tolerance) => difference <= tolerance,
+            Tolerance::Relative(tolerance) => difference <= tolerance * a.abs().max(b.abs()),
+            Tolerance::Ulps(ulps) => !difference.is_nan() && ulps_between(a, b, digits) <= ulps as u128,
+        }
+    }
+
+}
+
+// Number of representable values between a and b at the given precision
+fn ulps_between(a: f64, b: f64, digits: u32) -> u128 {
+    if digits <= f32::MANTISSA_DIGITS {
+        (ordered_bits_f32(a as f32) as i128 - ordered_bits_f32(b as f32) as i128).unsigned_abs()
+    } else {
+        (ordered_bits(a) as i128 - ordered_bits(b) as i128).unsigned_abs()
+    }
+}
+
+// Maps the sign-magnitude bit pattern to integers with the same ordering as the floats, both zeros map to 0
+fn ordered_bits(x: f64) -> i64 {
+    let bits = x.to_bits() as i64;
+    if bits < 0 { i64::MIN - bits } else { bits }
+}
+
+fn ordered_bits_f32(x: f32) -> i32 {
+    let bits = x.to_bits() as i32;
+    if bits < 0 { i32::MIN - bits } else { bits }
+}
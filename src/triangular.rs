@@ -0,0 +1,126 @@
+use std::fmt;
+
+use crate::numeric::{ Real, Float };
+use crate::matrix::Matrix;
+use crate::square::Square;
+
+#[derive(Debug, PartialEq)]
+pub enum TriangularSolveError {
+    MatrixIsNotSquare,
+    MatrixIsSingular,
+    DimensionMismatch,
+}
+
+impl fmt::Display for TriangularSolveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TriangularSolveError::MatrixIsNotSquare => {
+                write!(f, "Triangular solve failed because matrix is not square.")
+            },
+            TriangularSolveError::MatrixIsSingular => {
+                write!(f, "Triangular solve failed because a diagonal element is zero.")
+            },
+            TriangularSolveError::DimensionMismatch => {
+                write!(f, "Right hand side dimensions are not consistent with the triangular matrix.")
+            },
+        }
+    }
+}
+
+impl std::error::Error for TriangularSolveError {}
+
+pub trait Triangular {
+    fn is_lower_triangular(&self) -> bool;
+    fn is_upper_triangular(&self) -> bool;
+    // Only the lower triangle (diagonal included) of self is read
+    fn forward_substitution<S>(&self, b: &Matrix<S>) -> Result<Matrix<f64>, TriangularSolveError> where S: Real;
+    // Only the upper triangle (diagonal included) of self is read
+    fn back_substitution<S>(&self, b: &Matrix<S>) -> Result<Matrix<f64>, TriangularSolveError> where S: Real;
+}
+
+impl<T> Triangular for Matrix<T> where T: Real {
+    fn is_lower_triangular(&self) -> bool {
+        if !self.is_square() { return false }
+        for i in 0..self.rows() {
+            for j in (i + 1)..self.cols() {
+                if self[(i, j)] != T::zero() { return false }
+            }
+        }
+        true
+    }
+
+    fn is_upper_triangular(&self) -> bool {
+        if !self.is_square() { return false }
+        for i in 0..self.rows() {
+            for j in 0..i {
+                if self[(i, j)] != T::zero() { return false }
+            }
+        }
+        true
+    }
+
+    fn forward_substitution<S>(&self, b: &Matrix<S>) -> Result<Matrix<f64>, TriangularSolveError> where S: Real {
+        let l = self.copy_to::<f64>().unwrap();
+        let mut x = b.copy_to::<f64>().unwrap();
+        check_system(&l, &x)?;
+        solve_lower_in_place(&l, &mut x);
+        Ok(x)
+    }
+
+    fn back_substitution<S>(&self, b: &Matrix<S>) -> Result<Matrix<f64>, TriangularSolveError> where S: Real {
+        let u = self.copy_to::<f64>().unwrap();
+        let mut x = b.copy_to::<f64>().unwrap();
+        check_system(&u, &x)?;
+        solve_upper_in_place(&u, &mut x);
+        Ok(x)
+    }
+}
+
+pub(crate) fn check_system(a: &Matrix<f64>, b: &Matrix<f64>) -> Result<(), TriangularSolveError> {
+    if !a.is_square() { return Err(TriangularSolveError::MatrixIsNotSquare) }
+    if a.rows() != b.rows() { return Err(TriangularSolveError::DimensionMismatch) }
+    if (0..a.rows()).any(|k| a[(k, k)] == 0f64) { return Err(TriangularSolveError::MatrixIsSingular) }
+    Ok(())
+}
+
+// Solves L·X = B, overwriting B with X
+pub(crate) fn solve_lower_in_place<F>(l: &Matrix<F>, b: &mut Matrix<F>) where F: Float {
+    let n = l.rows();
+    for j in 0..b.cols() {
+        for i in 0..n {
+            let mut sum = b[(i, j)];
+            for k in 0..i {
+                sum -= l[(i, k)] * b[(k, j)];
+            }
+            b[(i, j)] = sum / l[(i, i)];
+        }
+    }
+}
+
+// Solves Lᵀ·X = B, overwriting B with X, without forming Lᵀ
+pub(crate) fn solve_lower_transpose_in_place<F>(l: &Matrix<F>, b: &mut Matrix<F>) where F: Float {
+    let n = l.rows();
+    for j in 0..b.cols() {
+        for i in (0..n).rev() {
+            let mut sum = b[(i, j)];
+            for k in (i + 1)..n {
+                sum -= l[(k, i)] * b[(k, j)];
+            }
+            b[(i, j)] = sum / l[(i, i)];
+        }
+    }
+}
+
+// Solves U·X = B, overwriting B with X
+pub(crate) fn solve_upper_in_place<F>(u: &Matrix<F>, b: &mut Matrix<F>) where F: Float {
+    let n = u.rows();
+    for j in 0..b.cols() {
+        for i in (0..n).rev() {
+            let mut sum = b[(i, j)];
+            for k in (i + 1)..n {
+                sum -= u[(i, k)] * b[(k, j)];
+            }
+            b[(i, j)] = sum / u[(i, i)];
+        }
+    }
+}
@@ -0,0 +1,229 @@
+use std::ops;
+use std::ops::RangeBounds;
+
+use crate::numeric::Numeric;
+use crate::matrix::{ Matrix, MatrixIndexOutOfBoundsError, resolve_range };
+
+#[derive(Debug, Clone, Copy)]
+struct Layout {
+    offset: usize,
+    rows: usize,
+    cols: usize,
+    row_stride: usize,
+    col_stride: usize,
+}
+
+impl Layout {
+    fn new(len: usize, offset: usize, rows: usize, cols: usize, row_stride: usize, col_stride: usize) -> Result<Self, MatrixIndexOutOfBoundsError> {
+        if rows == 0 || cols == 0 { return Err(MatrixIndexOutOfBoundsError) }
+        let last = (rows - 1).checked_mul(row_stride)
+            .and_then(|r| (cols - 1).checked_mul(col_stride).and_then(|c| r.checked_add(c)))
+            .and_then(|n| n.checked_add(offset))
+            .ok_or(MatrixIndexOutOfBoundsError)?;
+        if last >= len { return Err(MatrixIndexOutOfBoundsError) }
+        Ok(Layout { offset, rows, cols, row_stride, col_stride })
+    }
+
+    fn position(&self, index: (usize, usize)) -> usize {
+        let (row, col) = index;
+        if row >= self.rows || col >= self.cols {
+            panic!("Index out of bounds");
+        }
+        self.offset + row * self.row_stride + col * self.col_stride
+    }
+
+    fn transpose(self) -> Self {
+        Layout {
+            offset: self.offset,
+            rows: self.cols,
+            cols: self.rows,
+            row_stride: self.col_stride,
+            col_stride: self.row_stride,
+        }
+    }
+
+    fn block<R, C>(&self, row_range: R, col_range: C) -> Result<Self, MatrixIndexOutOfBoundsError>
+    where R: RangeBounds<usize>, C: RangeBounds<usize> {
+        let (row_start, row_end) = resolve_range(&row_range, self.rows)?;
+        let (col_start, col_end) = resolve_range(&col_range, self.cols)?;
+        Ok(Layout {
+            offset: self.offset + row_start * self.row_stride + col_start * self.col_stride,
+            rows: row_end - row_start,
+            cols: col_end - col_start,
+            row_stride: self.row_stride,
+            col_stride: self.col_stride,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct MatrixView<'a, T> where T: Numeric {
+    data: &'a [T],
+    layout: Layout,
+}
+
+impl<'a, T> MatrixView<'a, T> where T: Numeric {
+
+    pub fn new(data: &'a [T], offset: usize, rows: usize, cols: usize, row_stride: usize, col_stride: usize) -> Result<Self, MatrixIndexOutOfBoundsError> {
+        let layout = Layout::new(data.len(), offset, rows, cols, row_stride, col_stride)?;
+        Ok(MatrixView { data, layout })
+    }
+
+    pub fn rows(&self) -> usize {
+        self.layout.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.layout.cols
+    }
+
+    pub fn offset(&self) -> usize {
+        self.layout.offset
+    }
+
+    pub fn row_stride(&self) -> usize {
+        self.layout.row_stride
+    }
+
+    pub fn col_stride(&self) -> usize {
+        self.layout.col_stride
+    }
+
+    pub fn transpose(self) -> MatrixView<'a, T> {
+        MatrixView { data: self.data, layout: self.layout.transpose() }
+    }
+
+    pub fn subview<R, C>(&self, row_range: R, col_range: C) -> Result<MatrixView<'a, T>, MatrixIndexOutOfBoundsError>
+    where R: RangeBounds<usize>, C: RangeBounds<usize> {
+        Ok(MatrixView { data: self.data, layout: self.layout.block(row_range, col_range)? })
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> + '_ {
+        let data = self.data;
+        let layout = self.layout;
+        (0..layout.rows * layout.cols).map(move |n| &data[layout.position((n / layout.cols, n % layout.cols))])
+    }
+
+    pub fn to_matrix(&self) -> Matrix<T> {
+        Matrix::from_parts(self.rows(), self.cols(), self.iter().copied().collect())
+    }
+
+    pub fn copy_to<S>(&self) -> Matrix<S> where S: Numeric {
+        Matrix::from_parts(self.rows(), self.cols(), self.iter().map(|&element| element.to::<S>()).collect())
+    }
+
+}
+
+impl<T> ops::Index<(usize, usize)> for MatrixView<'_, T> where T: Numeric {
+    type Output = T;
+
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        &self.data[self.layout.position(index)]
+    }
+}
+
+#[derive(Debug)]
+pub struct MatrixViewMut<'a, T> where T: Numeric {
+    data: &'a mut [T],
+    layout: Layout,
+}
+
+impl<'a, T> MatrixViewMut<'a, T> where T: Numeric {
+
+    pub fn new(data: &'a mut [T], offset: usize, rows: usize, cols: usize, row_stride: usize, col_stride: usize) -> Result<Self, MatrixIndexOutOfBoundsError> {
+        let layout = Layout::new(data.len(), offset, rows, cols, row_stride, col_stride)?;
+        Ok(MatrixViewMut { data, layout })
+    }
+
+    pub fn rows(&self) -> usize {
+        self.layout.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.layout.cols
+    }
+
+    pub fn offset(&self) -> usize {
+        self.layout.offset
+    }
+
+    pub fn row_stride(&self) -> usize {
+        self.layout.row_stride
+    }
+
+    pub fn col_stride(&self) -> usize {
+        self.layout.col_stride
+    }
+
+    pub fn as_view(&self) -> MatrixView<'_, T> {
+        MatrixView { data: self.data, layout: self.layout }
+    }
+
+    pub fn transpose(self) -> MatrixViewMut<'a, T> {
+        MatrixViewMut { data: self.data, layout: self.layout.transpose() }
+    }
+
+    pub fn subview_mut<R, C>(&mut self, row_range: R, col_range: C) -> Result<MatrixViewMut<'_, T>, MatrixIndexOutOfBoundsError>
+    where R: RangeBounds<usize>, C: RangeBounds<usize> {
+        let layout = self.layout.block(row_range, col_range)?;
+        Ok(MatrixViewMut { data: self.data, layout })
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
+        let data = &*self.data;
+        let layout = self.layout;
+        (0..layout.rows * layout.cols).map(move |n| &data[layout.position((n / layout.cols, n % layout.cols))])
+    }
+
+    pub fn to_matrix(&self) -> Matrix<T> {
+        self.as_view().to_matrix()
+    }
+
+    pub fn fill(&mut self, value: T) {
+        for i in 0..self.rows() {
+            for j in 0..self.cols() {
+                self[(i, j)] = value;
+            }
+        }
+    }
+
+}
+
+impl<T> ops::Index<(usize, usize)> for MatrixViewMut<'_, T> where T: Numeric {
+    type Output = T;
+
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        &self.data[self.layout.position(index)]
+    }
+}
+
+impl<T> ops::IndexMut<(usize, usize)> for MatrixViewMut<'_, T> where T: Numeric {
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
+        &mut self.data[self.layout.position(index)]
+    }
+}
+
+impl<T> Matrix<T> where T: Numeric {
+
+    pub fn view(&self) -> MatrixView<'_, T> {
+        MatrixView::new(self.data(), 0, self.rows(), self.cols(), self.cols(), 1).unwrap()
+    }
+
+    pub fn view_mut(&mut self) -> MatrixViewMut<'_, T> {
+        let (rows, cols) = (self.rows(), self.cols());
+        MatrixViewMut::new(self.data_mut(), 0, rows, cols, cols, 1).unwrap()
+    }
+
+    pub fn submatrix_view<R, C>(&self, row_range: R, col_range: C) -> Result<MatrixView<'_, T>, MatrixIndexOutOfBoundsError>
+    where R: RangeBounds<usize>, C: RangeBounds<usize> {
+        self.view().subview(row_range, col_range)
+    }
+
+    pub fn submatrix_view_mut<R, C>(&mut self, row_range: R, col_range: C) -> Result<MatrixViewMut<'_, T>, MatrixIndexOutOfBoundsError>
+    where R: RangeBounds<usize>, C: RangeBounds<usize> {
+        let view = self.view_mut();
+        let layout = view.layout.block(row_range, col_range)?;
+        Ok(MatrixViewMut { data: view.data, layout })
+    }
+
+}
@@ -0,0 +1,66 @@
+use pathsim::prelude::*;
+use pathsim::{ CholeskyDecompositionError, EigenDecompositionError, MatrixDefinitionError, MatrixIndexOutOfBoundsError };
+
+#[test]
+fn test_prelude_builds_matrices() {
+    let m: Matrix<i32> = MatrixBuilder::new().from_vec(2, 3, vec![0, 1, 2, 3, 4, 5]).build().unwrap();
+    assert_eq!(m.rows(), 2);
+    assert_eq!(m.cols(), 3);
+    assert_eq!(m[(1, 2)], 5);
+    assert!(!m.is_square());
+    assert!(matches!(
+        MatrixBuilder::<f64>::new().from_vec(2, 2, vec![1f64]).build(),
+        Err(MatrixDefinitionError::DataLengthDoesNotMatchRowsTimesCols)
+    ));
+}
+
+#[test]
+fn test_prelude_swap() {
+    let mut m = MatrixBuilder::new().from_mat(vec![vec![1, 2], vec![3, 4]]).build().unwrap();
+    m.swap_rows(0, 1).unwrap();
+    m.swap_cols(0, 1).unwrap();
+    assert_eq!(m, MatrixBuilder::new().from_mat(vec![vec![4, 3], vec![2, 1]]).build().unwrap());
+    assert!(matches!(m.swap_rows(0, 2), Err(MatrixIndexOutOfBoundsError)));
+}
+
+#[test]
+fn test_prelude_symmetric_and_definite() {
+    let a = MatrixBuilder::new().from_mat(
+        vec![
+            vec![4f64, 1f64, 2f64],
+            vec![1f64, 5f64, 3f64],
+            vec![2f64, 3f64, 6f64],
+        ]
+    ).build().unwrap();
+    assert!(a.is_symmetric());
+    assert!(a.is_positive_definite());
+    let (d, _) = a.eigen_decomposition(Algorithm::Jacobi).unwrap();
+    assert_eq!(d.len(), 3);
+    assert!(a.cholesky().is_ok());
+
+    let b = MatrixBuilder::new().from_mat(vec![vec![0, 1], vec![1, 3]]).build().unwrap();
+    assert_eq!(b.cholesky(), Err(CholeskyDecompositionError::LeadingMinorIsNotPositive { index: 0, pivot: 0f64 }));
+
+    let c = MatrixBuilder::new().from_mat(vec![vec![0, 1], vec![2, 3]]).build().unwrap();
+    assert!(matches!(c.eigen_decomposition(Algorithm::Jacobi), Err(EigenDecompositionError)));
+}
+
+#[test]
+fn test_generic_over_numeric() {
+    fn trace<T: Numeric>(m: &Matrix<T>) -> T {
+        let mut sum = T::zero();
+        for k in 0..m.rows() { sum = sum + m[(k, k)]; }
+        sum
+    }
+    let m = MatrixBuilder::<i64>::new().identity(4).build().unwrap();
+    assert_eq!(trace(&m), 4i64);
+    assert_eq!(trace(&m.copy_to::<f32>().unwrap()), 4f32);
+}
+
+#[test]
+fn test_approx_assertion_macro() {
+    let a = MatrixBuilder::new().from_mat(vec![vec![4f32, 2f32], vec![2f32, 3f32]]).build().unwrap();
+    let l = a.cholesky().unwrap().into_l();
+    pathsim::assert_matrix_approx_eq!(&l * &l.transpose(), a, Tolerance::Ulps(4));
+    assert_eq!(l.max_abs_diff(&l).unwrap().difference, 0f64);
+}